@@ -0,0 +1,160 @@
+use jni::objects::{JObject, JValue};
+
+use crate::{
+    class::Class,
+    classpool::ClassPool,
+    errors::{HierError, HierResult as Result},
+};
+
+/// Type of an argument added to [ArgsBuilder], either a primitive type's descriptor or the
+/// [Class] of an object argument.
+#[derive(Clone)]
+enum ArgType {
+    Primitive(&'static str),
+    Object(Class),
+}
+
+/// Builds method arguments along with the method descriptor, so [JValue]s and their types
+/// declared in the descriptor never mismatch.
+///
+/// # Example
+///
+/// ```rs
+/// let mut cp = ClassPool::from_permanent_env()?;
+/// let string_class = cp.lookup_class("java.lang.String")?;
+/// let int_class = cp.lookup_class("int")?;
+/// let hex = cp.new_string("ff")?;
+/// let args = ArgsBuilder::new().arg_obj(&string_class, &hex).arg_int(16);
+/// let (descriptor, values) = args.build(&mut cp, &int_class)?;
+/// let value = cp
+///     .call_static_method("java/lang/Integer", "parseInt", descriptor, values)?
+///     .i()?;
+///
+/// assert_eq!(value, 255);
+/// ```
+#[derive(Clone, Default)]
+pub struct ArgsBuilder<'local, 'obj_ref> {
+    types: Vec<ArgType>,
+    values: Vec<JValue<'local, 'obj_ref>>,
+}
+
+macro_rules! __impl_arg_primitive {
+    ($name:ident, $ty:ty, $desc:literal) => {
+        #[doc = concat!("Adds a `", stringify!($ty), "` argument.")]
+        pub fn $name(self, value: $ty) -> Self {
+            self.arg(ArgType::Primitive($desc), value.into())
+        }
+    };
+}
+
+impl<'local, 'obj_ref> ArgsBuilder<'local, 'obj_ref> {
+    /// Creates an empty [ArgsBuilder].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    __impl_arg_primitive!(arg_boolean, bool, "Z");
+    __impl_arg_primitive!(arg_byte, i8, "B");
+    __impl_arg_primitive!(arg_char, u16, "C");
+    __impl_arg_primitive!(arg_short, i16, "S");
+    __impl_arg_primitive!(arg_int, i32, "I");
+    __impl_arg_primitive!(arg_long, i64, "J");
+    __impl_arg_primitive!(arg_float, f32, "F");
+    __impl_arg_primitive!(arg_double, f64, "D");
+
+    /// Adds an object argument declared as given [Class] in the descriptor, which is checked
+    /// against the object's runtime class when building, see [build](Self::build). `null` is
+    /// accepted as any class.
+    pub fn arg_obj(self, class: &Class, obj: &'obj_ref JObject<'local>) -> Self {
+        self.arg(ArgType::Object(class.clone()), JValue::Object(obj))
+    }
+
+    fn arg(mut self, ty: ArgType, value: JValue<'local, 'obj_ref>) -> Self {
+        self.types.push(ty);
+        self.values.push(value);
+        self
+    }
+
+    /// Builds the method descriptor with given return type, and returns it along with
+    /// added arguments.
+    ///
+    /// # Errors
+    ///
+    /// If an object argument is not an instance of its declared [Class],
+    /// [HierError::ArgumentTypeMismatch] is returned.
+    pub fn build(
+        &self,
+        cp: &mut ClassPool<'_>,
+        return_type: &Class,
+    ) -> Result<(String, &[JValue<'local, 'obj_ref>])> {
+        let mut descriptor = String::from("(");
+
+        for (i, (ty, value)) in self.types.iter().zip(&self.values).enumerate() {
+            match (ty, value) {
+                (ArgType::Object(class), JValue::Object(obj)) => {
+                    let mut class = class.clone();
+
+                    if !obj.is_null() && !class.is_instance_of_cached(cp, obj)? {
+                        return Err(HierError::ArgumentTypeMismatch(i, class.name(cp)?));
+                    }
+
+                    descriptor.push_str(&class.descriptor(cp)?);
+                }
+                (ArgType::Primitive(desc), _) => descriptor.push_str(desc),
+                _ => unreachable!("object argument must be paired with its class"),
+            }
+        }
+
+        descriptor.push(')');
+        descriptor.push_str(&return_type.descriptor(cp)?);
+
+        Ok((descriptor, &self.values))
+    }
+}
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use crate::{
+        args::ArgsBuilder,
+        classpool::ClassPool,
+        errors::{HierError, HierResult},
+    };
+
+    #[test]
+    fn test_build() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let string_class = cp.lookup_class("java.lang.String")?;
+        let int_class = cp.lookup_class("int")?;
+        let hex = cp.new_string("ff")?;
+        let args = ArgsBuilder::new().arg_obj(&string_class, &hex).arg_int(16);
+        let (descriptor, values) = args.build(&mut cp, &int_class)?;
+
+        assert_eq!(descriptor, "(Ljava/lang/String;I)I");
+
+        let value = cp
+            .call_static_method("java/lang/Integer", "parseInt", descriptor, values)?
+            .i()?;
+
+        assert_eq!(value, 255);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_type_mismatch() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let string_class = cp.lookup_class("java.lang.String")?;
+        let void_class = cp.lookup_class("void")?;
+        let integer = cp.new_object("java/lang/Integer", "(I)V", &[5.into()])?;
+        let args = ArgsBuilder::new()
+            .arg_long(0)
+            .arg_obj(&string_class, &integer);
+
+        assert!(matches!(
+            args.build(&mut cp, &void_class),
+            Err(HierError::ArgumentTypeMismatch(1, class_name)) if class_name == "java.lang.String"
+        ));
+
+        Ok(())
+    }
+}
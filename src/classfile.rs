@@ -0,0 +1,175 @@
+//! Minimal class file parsing for informations `java.lang.Class` doesn't expose, see the
+//! [class file format](https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html).
+
+const MAGIC: u32 = 0xCAFEBABE;
+
+/// A cursor over class file bytes, every read returns [None] once bytes are exhausted.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+
+        let (bytes, rest) = self.bytes.split_at(len);
+
+        self.bytes = rest;
+
+        Some(bytes)
+    }
+
+    fn u1(&mut self) -> Option<u8> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    fn u2(&mut self) -> Option<u16> {
+        self.bytes(2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u4(&mut self) -> Option<u32> {
+        self.bytes(4)
+            .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Skips fields or methods, both share the same structure.
+    fn skip_members(&mut self) -> Option<()> {
+        for _ in 0..self.u2()? {
+            self.bytes(6)?;
+            self.skip_attributes()?;
+        }
+
+        Some(())
+    }
+
+    fn skip_attributes(&mut self) -> Option<()> {
+        for _ in 0..self.u2()? {
+            self.u2()?;
+
+            let len = self.u4()? as usize;
+
+            self.bytes(len)?;
+        }
+
+        Some(())
+    }
+}
+
+/// Reads the `SourceFile` attribute of a class file, returns [None] if the attribute is
+/// absent or the class file is malformed.
+pub(crate) fn source_file(bytes: &[u8]) -> Option<String> {
+    let mut reader = Reader { bytes };
+
+    if reader.u4()? != MAGIC {
+        return None;
+    }
+
+    // minor_version, major_version
+    reader.bytes(4)?;
+
+    let constant_pool_count = reader.u2()? as usize;
+    let mut utf8_constants = vec![None; constant_pool_count];
+    let mut i = 1;
+
+    while i < constant_pool_count {
+        match reader.u1()? {
+            1 => {
+                let len = reader.u2()? as usize;
+
+                utf8_constants[i] = Some(reader.bytes(len)?);
+            }
+            7 | 8 | 16 | 19 | 20 => {
+                reader.bytes(2)?;
+            }
+            15 => {
+                reader.bytes(3)?;
+            }
+            3 | 4 | 9 | 10 | 11 | 12 | 17 | 18 => {
+                reader.bytes(4)?;
+            }
+            // Long and Double take 2 constant pool entries
+            5 | 6 => {
+                reader.bytes(8)?;
+                i += 1;
+            }
+            _ => return None,
+        }
+
+        i += 1;
+    }
+
+    // access_flags, this_class, super_class
+    reader.bytes(6)?;
+
+    let interfaces_count = reader.u2()? as usize;
+
+    reader.bytes(interfaces_count * 2)?;
+    reader.skip_members()?;
+    reader.skip_members()?;
+
+    let utf8_constant = |index: u16| utf8_constants.get(index as usize).copied().flatten();
+
+    for _ in 0..reader.u2()? {
+        let name_index = reader.u2()?;
+        let len = reader.u4()? as usize;
+        let info = reader.bytes(len)?;
+
+        if utf8_constant(name_index) == Some(b"SourceFile") && len == 2 {
+            let source_file = utf8_constant(u16::from_be_bytes([info[0], info[1]]))?;
+
+            return Some(String::from_utf8_lossy(source_file).into_owned());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::classfile::source_file;
+
+    /// A handcrafted class file of class `A` declared in `A.java`, without any field and method.
+    const CLASS_FILE: &[u8] = &[
+        0xCA, 0xFE, 0xBA, 0xBE, // magic
+        0x00, 0x00, 0x00, 0x34, // minor_version, major_version
+        0x00, 0x07, // constant_pool_count
+        0x07, 0x00, 0x02, // #1 Class #2
+        0x01, 0x00, 0x01, b'A', // #2 Utf8 "A"
+        0x07, 0x00, 0x04, // #3 Class #4
+        0x01, 0x00, 0x10, b'j', b'a', b'v', b'a', b'/', b'l', b'a', b'n', b'g', b'/', b'O', b'b',
+        b'j', b'e', b'c', b't', // #4 Utf8 "java/lang/Object"
+        0x01, 0x00, 0x0A, b'S', b'o', b'u', b'r', b'c', b'e', b'F', b'i', b'l', b'e', // #5
+        0x01, 0x00, 0x06, b'A', b'.', b'j', b'a', b'v', b'a', // #6 Utf8 "A.java"
+        0x00, 0x20, // access_flags
+        0x00, 0x01, 0x00, 0x03, // this_class, super_class
+        0x00, 0x00, // interfaces_count
+        0x00, 0x00, // fields_count
+        0x00, 0x00, // methods_count
+        0x00, 0x01, // attributes_count
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x00, 0x06, // SourceFile #6
+    ];
+
+    #[test]
+    fn test_source_file() {
+        assert_eq!(source_file(CLASS_FILE).as_deref(), Some("A.java"));
+    }
+
+    #[test]
+    fn test_source_file_malformed() {
+        assert_eq!(source_file(&CLASS_FILE[..CLASS_FILE.len() - 1]), None);
+        assert_eq!(source_file(&CLASS_FILE[4..]), None);
+        assert_eq!(source_file(&[]), None);
+    }
+
+    #[test]
+    fn test_source_file_absent() {
+        let mut class_file = CLASS_FILE[..CLASS_FILE.len() - 10].to_vec();
+
+        class_file.extend([0x00, 0x00]);
+
+        assert_eq!(source_file(&class_file), None);
+    }
+}
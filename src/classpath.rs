@@ -1,5 +1,9 @@
+use std::borrow::Cow;
+
 use phf::phf_map;
 
+use crate::errors::ClassPathError;
+
 pub(crate) static PRIMITIVE_TYPES_TO_DESC: phf::Map<&'static str, &'static str> = phf_map! {
     "void" => "V",
     "boolean" => "Z",
@@ -33,6 +37,7 @@ impl ClassPath {
     pub fn convert(&self) -> Self {
         match self {
             Self::Java(cp) => {
+                let cp = Self::normalize_java(cp);
                 let mut jni_cp = cp.replace('.', "/").replace("[]", "");
                 let array_dim = cp.matches("[]").count();
 
@@ -47,21 +52,26 @@ impl ClassPath {
                 Self::JNI(jni_cp)
             }
             Self::JNI(cp) => {
-                let mut java_cp = cp.replace('/', ".").replace('[', "");
-                let array_dim = cp.matches('[').count();
+                let element_desc = cp.trim_start_matches('[');
+                let array_dim = cp.len() - element_desc.len();
 
-                if array_dim > 0 {
-                    if !PRIMITIVE_TYPES_TO_DESC
-                        .values()
-                        .any(|desc| java_cp.starts_with(desc))
-                    {
-                        java_cp = java_cp.chars().skip(1).take_while(|c| *c != ';').collect();
-                    }
-
-                    java_cp = format!("{java_cp}{}", "[]".repeat(array_dim));
+                if array_dim == 0 {
+                    return Self::Java(cp.replace('/', "."));
                 }
 
-                Self::Java(java_cp)
+                let element_cp = match PRIMITIVE_TYPES_TO_DESC
+                    .entries()
+                    .find(|(_, desc)| **desc == element_desc)
+                {
+                    Some((primitive, _)) => primitive.to_string(),
+                    None => element_desc
+                        .strip_prefix('L')
+                        .unwrap_or(element_desc)
+                        .trim_end_matches(';')
+                        .replace('/', "."),
+                };
+
+                Self::Java(format!("{element_cp}{}", "[]".repeat(array_dim)))
             }
         }
     }
@@ -79,6 +89,192 @@ impl ClassPath {
             _ => self,
         }
     }
+
+    /// Decodes a field descriptor into [ClassPath::JNI], e.g. `Ljava/lang/String;` becomes
+    /// `java/lang/String`, `I` becomes `int`, and array descriptors like `[I` are kept as is.
+    ///
+    /// `V` is also accepted and decoded into `void` so return descriptors can be decoded
+    /// as well.
+    pub fn from_descriptor(desc: &str) -> Result<Self, ClassPathError> {
+        if desc.is_empty() {
+            return Err(ClassPathError::EmptyPath);
+        }
+
+        if desc.starts_with('[') {
+            Self::validate_jni(desc)?;
+
+            return Ok(Self::JNI(desc.to_string()));
+        }
+
+        if let Some((primitive, _)) = PRIMITIVE_TYPES_TO_DESC
+            .entries()
+            .find(|(_, prim_desc)| **prim_desc == desc)
+        {
+            return Ok(Self::JNI(primitive.to_string()));
+        }
+
+        match desc
+            .strip_prefix('L')
+            .and_then(|desc| desc.strip_suffix(';'))
+        {
+            Some(name) => {
+                Self::validate_binary_name(desc, name, '/')?;
+
+                Ok(Self::JNI(name.to_string()))
+            }
+            None => Err(ClassPathError::BadDescriptor(desc.to_string())),
+        }
+    }
+
+    /// Determines if both class paths represent the same class regardless of their formats,
+    /// both class paths are normalized into [ClassPath::JNI] before comparing.
+    ///
+    /// Unlike [PartialEq], `ClassPath::Java("java.lang.Object")` and
+    /// `ClassPath::JNI("java/lang/Object")` are equivalent.
+    pub fn equivalent(&self, other: &ClassPath) -> bool {
+        self.clone().as_jni() == other.clone().as_jni()
+    }
+
+    /// Returns the simple name of class path, which is the last segment separated by either
+    /// `.` or `$`, array dimensions are preserved.
+    ///
+    /// For example, `java.util.Map$Entry[]` has simple name `Entry[]`.
+    pub fn simple_name(&self) -> String {
+        let java_cp: String = self.clone().as_java().into();
+        let (element_cp, array_dims) = Self::split_array_dims(&java_cp);
+        let simple_name = element_cp.rsplit(['.', '$']).next().unwrap_or(element_cp);
+
+        format!("{simple_name}{array_dims}")
+    }
+
+    /// Returns the package of class path, which is everything before the last `.`, or an
+    /// empty string if class path is in default package or is a primitive type.
+    ///
+    /// For example, `java.util.Map$Entry[]` is in package `java.util`.
+    pub fn package(&self) -> String {
+        let java_cp: String = self.clone().as_java().into();
+        let (element_cp, _) = Self::split_array_dims(&java_cp);
+
+        element_cp
+            .rsplit_once('.')
+            .map(|(package, _)| package.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Validates class path, checks if it's empty, contains illegal characters, is not a
+    /// valid array descriptor, or represents an array of `void`.
+    ///
+    /// Whitespaces around and within array brackets of [ClassPath::Java] are ignored, e.g.
+    /// `int [ ]` is valid and converted as `int[]`.
+    pub fn validate(&self) -> Result<(), ClassPathError> {
+        match self {
+            Self::Java(cp) => Self::validate_java(cp),
+            Self::JNI(cp) => Self::validate_jni(cp),
+        }
+    }
+
+    fn validate_java(cp: &str) -> Result<(), ClassPathError> {
+        if cp.is_empty() {
+            return Err(ClassPathError::EmptyPath);
+        }
+
+        let normalized_cp = Self::normalize_java(cp);
+        let (element_cp, array_dims) = Self::split_array_dims(&normalized_cp);
+
+        if element_cp.is_empty() && !array_dims.is_empty() {
+            return Err(ClassPathError::BadDescriptor(cp.to_string()));
+        }
+
+        if array_dims != "[]".repeat(array_dims.matches("[]").count()) {
+            let illegal_char = array_dims.replace("[]", "").chars().next().unwrap_or('[');
+
+            return Err(ClassPathError::IllegalCharacter(
+                cp.to_string(),
+                illegal_char,
+            ));
+        }
+
+        Self::validate_binary_name(cp, element_cp, '.')?;
+
+        if element_cp == "void" && !array_dims.is_empty() {
+            return Err(ClassPathError::VoidArray(cp.to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn validate_jni(cp: &str) -> Result<(), ClassPathError> {
+        if cp.is_empty() {
+            return Err(ClassPathError::EmptyPath);
+        }
+
+        let element_desc = cp.trim_start_matches('[');
+
+        if element_desc.len() == cp.len() {
+            return Self::validate_binary_name(cp, element_desc, '/');
+        }
+
+        match element_desc {
+            "V" => Err(ClassPathError::VoidArray(cp.to_string())),
+            desc if PRIMITIVE_TYPES_TO_DESC
+                .values()
+                .any(|prim_desc| *prim_desc == desc) =>
+            {
+                Ok(())
+            }
+            desc if desc.starts_with('L') && desc.ends_with(';') && desc.len() > 2 => {
+                Self::validate_binary_name(cp, &desc[1..desc.len() - 1], '/')
+            }
+            _ => Err(ClassPathError::BadDescriptor(cp.to_string())),
+        }
+    }
+
+    /// Validates binary name (with given package separator) of a non-array class.
+    fn validate_binary_name(cp: &str, name: &str, separator: char) -> Result<(), ClassPathError> {
+        if name.is_empty() {
+            return Err(ClassPathError::EmptyPath);
+        }
+
+        for segment in name.split(separator) {
+            if segment.is_empty() {
+                return Err(ClassPathError::IllegalCharacter(cp.to_string(), separator));
+            }
+
+            if let Some(illegal_char) = segment
+                .chars()
+                .find(|c| matches!(c, '.' | '/' | ';' | '[' | ']') || c.is_whitespace())
+            {
+                return Err(ClassPathError::IllegalCharacter(
+                    cp.to_string(),
+                    illegal_char,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes whitespaces between element class path and array brackets, within array
+    /// brackets, and after array brackets of Java class path.
+    fn normalize_java(java_cp: &str) -> Cow<'_, str> {
+        match java_cp.find('[') {
+            Some(dims_start) => {
+                let (element_cp, array_dims) = java_cp.split_at(dims_start);
+                let array_dims = array_dims
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect::<String>();
+
+                Cow::Owned(format!("{}{array_dims}", element_cp.trim_end()))
+            }
+            None => Cow::Borrowed(java_cp),
+        }
+    }
+
+    /// Splits Java class path into element class path and its array dimensions.
+    fn split_array_dims(java_cp: &str) -> (&str, &str) {
+        java_cp.split_at(java_cp.find("[]").unwrap_or(java_cp.len()))
+    }
 }
 
 impl From<ClassPath> for String {
@@ -103,3 +299,182 @@ impl<'a> From<&'a str> for ClassPath {
         Self::Java(value.to_string())
     }
 }
+
+/// Types which can be converted into [ClassPath], and borrowed as a Java class path before
+/// conversion, so lookups of cached classes don't have to build a [ClassPath] at all.
+pub trait IntoClassPath: Into<ClassPath> {
+    /// Borrows as a Java class path, returns [None] if it's a JNI class path.
+    fn as_java_str(&self) -> Option<&str>;
+}
+
+impl IntoClassPath for ClassPath {
+    fn as_java_str(&self) -> Option<&str> {
+        match self {
+            Self::Java(cp) => Some(cp),
+            Self::JNI(_) => None,
+        }
+    }
+}
+
+impl IntoClassPath for String {
+    fn as_java_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl IntoClassPath for &str {
+    fn as_java_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::{
+        classpath::{ClassPath, PRIMITIVE_TYPES_TO_DESC},
+        errors::ClassPathError,
+    };
+
+    #[rstest]
+    #[case(ClassPath::Java("java.lang.Object".to_string()), "Object", "java.lang")]
+    #[case(ClassPath::Java("java.util.Map$Entry".to_string()), "Entry", "java.util")]
+    #[case(ClassPath::Java("a.b.Outer$Inner".to_string()), "Inner", "a.b")]
+    #[case(ClassPath::JNI("java/util/Map$Entry".to_string()), "Entry", "java.util")]
+    #[case(ClassPath::Java("java.lang.String[][]".to_string()), "String[][]", "java.lang")]
+    #[case(ClassPath::Java("Main".to_string()), "Main", "")]
+    #[case(ClassPath::Java("Main$Nested".to_string()), "Nested", "")]
+    #[case(ClassPath::Java("int[]".to_string()), "int[]", "")]
+    fn test_simple_name_and_package(
+        #[case] class_path: ClassPath,
+        #[case] simple_name: &'static str,
+        #[case] package: &'static str,
+    ) {
+        assert_eq!(class_path.simple_name(), simple_name);
+        assert_eq!(class_path.package(), package);
+    }
+
+    #[rstest]
+    #[case(ClassPath::Java("java.lang.Object".to_string()), ClassPath::JNI("java/lang/Object".to_string()), true)]
+    #[case(ClassPath::Java("java.lang.Object".to_string()), ClassPath::Java("java.lang.Object".to_string()), true)]
+    #[case(ClassPath::Java("int[][]".to_string()), ClassPath::JNI("[[I".to_string()), true)]
+    #[case(ClassPath::Java("java.lang.Object".to_string()), ClassPath::JNI("java/lang/String".to_string()), false)]
+    fn test_equivalent(#[case] a: ClassPath, #[case] b: ClassPath, #[case] equivalent: bool) {
+        assert_eq!(a.equivalent(&b), equivalent);
+        assert_eq!(b.equivalent(&a), equivalent);
+    }
+
+    #[test]
+    fn test_equivalent_is_not_equal() {
+        let java_cp = ClassPath::Java("java.lang.Object".to_string());
+        let jni_cp = ClassPath::JNI("java/lang/Object".to_string());
+
+        assert!(java_cp.equivalent(&jni_cp));
+        assert_ne!(java_cp, jni_cp);
+    }
+
+    #[rstest]
+    #[case(ClassPath::Java("java.lang.Object".to_string()))]
+    #[case(ClassPath::Java("java.util.Map$Entry[][]".to_string()))]
+    #[case(ClassPath::Java("int".to_string()))]
+    #[case(ClassPath::JNI("java/lang/Object".to_string()))]
+    #[case(ClassPath::JNI("[[I".to_string()))]
+    #[case(ClassPath::JNI("[Ljava/lang/String;".to_string()))]
+    #[case(ClassPath::Java("int[] ".to_string()))]
+    #[case(ClassPath::Java("int []".to_string()))]
+    #[case(ClassPath::Java("int[ ] [] ".to_string()))]
+    fn test_validate(#[case] class_path: ClassPath) {
+        assert_eq!(class_path.validate(), Ok(()));
+    }
+
+    #[rstest]
+    #[case(ClassPath::Java("".to_string()), ClassPathError::EmptyPath)]
+    #[case(ClassPath::Java("void[]".to_string()), ClassPathError::VoidArray("void[]".to_string()))]
+    #[case(ClassPath::Java("a..b".to_string()), ClassPathError::IllegalCharacter("a..b".to_string(), '.'))]
+    #[case(ClassPath::Java("a.b;".to_string()), ClassPathError::IllegalCharacter("a.b;".to_string(), ';'))]
+    #[case(ClassPath::Java("int[]x".to_string()), ClassPathError::IllegalCharacter("int[]x".to_string(), 'x'))]
+    #[case(ClassPath::Java("[]int".to_string()), ClassPathError::BadDescriptor("[]int".to_string()))]
+    #[case(ClassPath::Java("[]".to_string()), ClassPathError::BadDescriptor("[]".to_string()))]
+    #[case(ClassPath::Java("int[] x".to_string()), ClassPathError::IllegalCharacter("int[] x".to_string(), 'x'))]
+    #[case(ClassPath::Java("int ".to_string()), ClassPathError::IllegalCharacter("int ".to_string(), ' '))]
+    #[case(ClassPath::Java("java.lang. String".to_string()), ClassPathError::IllegalCharacter("java.lang. String".to_string(), ' '))]
+    #[case(ClassPath::JNI("[V".to_string()), ClassPathError::VoidArray("[V".to_string()))]
+    #[case(ClassPath::JNI("[Q".to_string()), ClassPathError::BadDescriptor("[Q".to_string()))]
+    fn test_validate_error(#[case] class_path: ClassPath, #[case] error: ClassPathError) {
+        assert_eq!(class_path.validate(), Err(error));
+    }
+
+    #[rstest]
+    #[case("Ljava/lang/String;", "java/lang/String")]
+    #[case("Ljava/util/Map$Entry;", "java/util/Map$Entry")]
+    #[case("I", "int")]
+    #[case("V", "void")]
+    #[case("[I", "[I")]
+    #[case("[[Ljava/lang/String;", "[[Ljava/lang/String;")]
+    fn test_from_descriptor(#[case] desc: &str, #[case] class_path: &str) {
+        assert_eq!(
+            ClassPath::from_descriptor(desc),
+            Ok(ClassPath::JNI(class_path.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("", ClassPathError::EmptyPath)]
+    #[case("Q", ClassPathError::BadDescriptor("Q".to_string()))]
+    #[case("java/lang/String", ClassPathError::BadDescriptor("java/lang/String".to_string()))]
+    #[case("L;", ClassPathError::EmptyPath)]
+    #[case("La//b;", ClassPathError::IllegalCharacter("La//b;".to_string(), '/'))]
+    #[case("[V", ClassPathError::VoidArray("[V".to_string()))]
+    fn test_from_descriptor_error(#[case] desc: &str, #[case] error: ClassPathError) {
+        assert_eq!(ClassPath::from_descriptor(desc), Err(error));
+    }
+
+    #[test]
+    fn test_convert_array_dimensions() {
+        let element_cps = PRIMITIVE_TYPES_TO_DESC
+            .entries()
+            .filter(|(primitive, _)| **primitive != "void")
+            .map(|(primitive, desc)| (primitive.to_string(), desc.to_string()))
+            .chain([(
+                "java.lang.String".to_string(),
+                "Ljava/lang/String;".to_string(),
+            )]);
+
+        for (element_cp, element_desc) in element_cps {
+            for dims in 1..=4 {
+                let java_cp = ClassPath::Java(format!("{element_cp}{}", "[]".repeat(dims)));
+                let jni_cp = ClassPath::JNI(format!("{}{element_desc}", "[".repeat(dims)));
+
+                assert_eq!(java_cp.convert(), jni_cp);
+                assert_eq!(jni_cp.convert(), java_cp);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("int[] ", "[I")]
+    #[case("int []", "[I")]
+    #[case("int[] [ ]", "[[I")]
+    #[case("java.lang.String []", "[Ljava/lang/String;")]
+    fn test_convert_normalized(#[case] java_cp: &str, #[case] jni_cp: &str) {
+        assert_eq!(
+            ClassPath::Java(java_cp.to_string()).convert(),
+            ClassPath::JNI(jni_cp.to_string())
+        );
+    }
+
+    #[rstest]
+    #[case("int[][]", "[[I")]
+    #[case("boolean[][][]", "[[[Z")]
+    #[case("java.lang.String[][]", "[[Ljava/lang/String;")]
+    #[case("java.util.Map$Entry", "java/util/Map$Entry")]
+    #[case("int", "int")]
+    fn test_convert(#[case] java_cp: &str, #[case] jni_cp: &str) {
+        let java_cp = ClassPath::Java(java_cp.to_string());
+        let jni_cp = ClassPath::JNI(jni_cp.to_string());
+
+        assert_eq!(java_cp.convert(), jni_cp);
+        assert_eq!(jni_cp.convert(), java_cp);
+    }
+}
@@ -1,32 +1,47 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug},
     ops::{Deref, DerefMut},
+    panic::{self, AssertUnwindSafe},
     sync::{Arc, Mutex},
 };
 
 use jni::{
-    objects::{JClass, JString, JValueGen},
-    signature::{JavaType, ReturnType},
-    JNIEnv,
+    objects::{GlobalRef, JClass, JObject, JObjectArray, JValue, JValueGen, JValueOwned},
+    signature::JavaType,
+    AttachGuard, JNIEnv, JavaVM,
 };
+use once_cell::sync::OnceCell;
+
+#[cfg(feature = "serde")]
+use crate::snapshot::ClassSnapshot;
 
 use crate::{
-    class::{Class, ClassInternal},
-    classpath::ClassPath,
+    class::{Class, ClassInternal, ClassKind},
+    classpath::{ClassPath, IntoClassPath},
 };
 use crate::{
     classpath::{DESC_TO_WRAPPER_CLASS_CP, PRIMITIVE_TYPES_TO_DESC},
-    errors::HierResult as Result,
+    errors::{HierError, HierResult as Result},
+    modifiers::Modifiers,
+    version::JavaVersion,
+    HierExt,
 };
 
-type ClassCache = HashMap<String, Arc<Mutex<ClassInternal>>>;
+type ClassCache = HashMap<Arc<str>, Arc<Mutex<ClassInternal>>>;
 
 pub struct ClassPool<'local> {
     jni_env: JNIEnv<'local>,
     class_cache: ClassCache,
+    interned_names: HashSet<Arc<str>>,
+    java_names: HashMap<String, Arc<str>>,
+    java_version: OnceCell<JavaVersion>,
 }
 
 impl<'local> ClassPool<'local> {
+    /// The maximum amount of cached class paths shown in [ClassPool]'s [Debug] output.
+    pub const DEBUG_CLASS_PATHS_LIMIT: usize = 32;
+
     #[cfg(feature = "invocation")]
     /// Constructs a new [`ClassPool`] by invoking a new [`JavaVM`](jni::JavaVM) and
     /// attaches its [`JNIEnv`] from permanently.
@@ -39,14 +54,182 @@ impl<'local> ClassPool<'local> {
         jni_env().map(|env| Self::from_exist_env(&env))
     }
 
+    #[cfg(feature = "invocation")]
+    /// Constructs a new [`ClassPool`] like [`from_permanent_env`](Self::from_permanent_env),
+    /// but the [`JavaVM`](jni::JavaVM) is invoked with `-Xcheck:jni` only if `check_jni` is
+    /// set. Disabling JNI checks reduces the overhead of every JNI call, which is preferred in
+    /// production.
+    ///
+    /// Only one [`JavaVM`](jni::JavaVM) can exist in a process, if it's already initialized
+    /// with a different `-Xcheck:jni` setting, or with custom arguments by
+    /// [`from_permanent_env_with`](Self::from_permanent_env_with), an
+    /// [`HierError::JvmAlreadyInitialized`] is returned.
+    pub fn from_permanent_env_checked(check_jni: bool) -> Result<Self> {
+        use crate::java_vm::jni_env_checked;
+
+        jni_env_checked(check_jni).map(|env| Self::from_exist_env(&env))
+    }
+
+    #[cfg(feature = "invocation")]
+    /// Constructs a new [`ClassPool`] by invoking a new [`JavaVM`](jni::JavaVM) with given
+    /// [`InitArgs`](jni::InitArgs) and attaches its [`JNIEnv`] from permanently, this allows
+    /// callers to control JVM options, e.g. class path, heap size and module flags.
+    ///
+    /// Only one [`JavaVM`](jni::JavaVM) can exist in a process, so this must be called before
+    /// any other [`ClassPool`] is constructed by [`from_permanent_env`](Self::from_permanent_env),
+    /// otherwise [`HierError::JvmAlreadyInitialized`] is returned. Later calls to
+    /// [`from_permanent_env`](Self::from_permanent_env) reuse the initialized JVM.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let jvm_args = InitArgsBuilder::new()
+    ///     .version(JNIVersion::V8)
+    ///     .option("-Djava.class.path=app.jar")
+    ///     .build()?;
+    /// let mut cp = ClassPool::from_permanent_env_with(jvm_args)?;
+    /// ```
+    pub fn from_permanent_env_with(jvm_args: jni::InitArgs) -> Result<Self> {
+        use crate::java_vm::jni_env_with;
+
+        jni_env_with(jvm_args).map(|env| Self::from_exist_env(&env))
+    }
+
+    #[cfg(feature = "invocation")]
+    /// Detaches current thread which is permanently attached by [`from_permanent_env`](Self::from_permanent_env),
+    /// this is useful for worker threads that come and go, since permanently attached threads
+    /// otherwise stay attached until they exit.
+    ///
+    /// The class cache is freed before detaching, later [`from_permanent_env`](Self::from_permanent_env)
+    /// calls will re-attach current thread.
+    ///
+    /// # Safety
+    ///
+    /// The [`JNIEnv`] of every [`ClassPool`] constructed on current thread, including the
+    /// ones cloned from it by [`from_exist_env`](Self::from_exist_env), is invalidated after
+    /// detaching, and must not be used afterward.
+    pub unsafe fn detach_current_thread(self) -> Result<()> {
+        use crate::java_vm::detach_current_thread;
+
+        drop(self);
+        detach_current_thread()
+    }
+
     /// Constructs a new [`ClassPool`] by cloning existed [`JNIEnv`].
     pub fn from_exist_env(jni_env: &JNIEnv<'local>) -> Self {
         Self {
             jni_env: unsafe { jni_env.unsafe_clone() },
             class_cache: HashMap::new(),
+            interned_names: HashSet::new(),
+            java_names: HashMap::new(),
+            java_version: OnceCell::new(),
+        }
+    }
+
+    /// Constructs a new [`ScopedClassPool`] by attaching current thread to given [`JavaVM`],
+    /// the thread is detached when the pool is dropped if it was freshly attached by this
+    /// call. If current thread is already attached, e.g. by [`from_permanent_env`](Self::from_permanent_env),
+    /// it stays attached after dropping.
+    ///
+    /// This suits short-lived work on threads which otherwise never interact with JVM, unlike
+    /// [`from_permanent_env`](Self::from_permanent_env) which keeps current thread attached.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// std::thread::spawn(move || -> HierResult<()> {
+    ///     let mut cp = ClassPool::from_vm_scoped(&vm)?;
+    ///     let mut class = cp.lookup_class("java.lang.Integer")?;
+    ///
+    ///     println!("{}", class.name(&mut cp)?);
+    ///
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn from_vm_scoped(vm: &'local JavaVM) -> Result<ScopedClassPool<'local>> {
+        let guard = vm.attach_current_thread()?;
+        let cp = Self::from_exist_env(&guard);
+
+        Ok(ScopedClassPool { cp, _guard: guard })
+    }
+
+    /// Attaches current thread to given [`JavaVM`] permanently and replaces the internal
+    /// [`JNIEnv`] with the newly attached one, the class cache is preserved since cached
+    /// classes are held by global references, which remain valid across threads.
+    ///
+    /// This is required after a [`ClassPool`] is moved to another thread, since [`JNIEnv`]
+    /// is only valid on the thread it's attached to.
+    pub fn reattach(&mut self, vm: &Arc<JavaVM>) -> Result<()> {
+        let jni_env = vm.attach_current_thread_permanently()?;
+
+        // Permanently attached env stays valid until current thread is detached, rather
+        // than being bound to the given JavaVM reference.
+        self.jni_env = unsafe { JNIEnv::from_raw(jni_env.get_raw())? };
+
+        Ok(())
+    }
+
+    /// Executes given closure within a new JNI local reference frame, which is able to hold
+    /// at least given amount of local references. All local references created within the
+    /// closure are freed after the frame is popped, while cached classes survive since they
+    /// are held by global references.
+    ///
+    /// This bounds local reference growth during bulk works, e.g. loading a large hierarchy.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let classes = cp.with_frame(64, |cp| {
+    ///     class_paths
+    ///         .iter()
+    ///         .map(|class_path| cp.lookup_class(*class_path))
+    ///         .collect::<Result<Vec<_>>>()
+    /// })?;
+    /// ```
+    pub fn with_frame<R, F>(&mut self, capacity: i32, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut ClassPool) -> Result<R>,
+    {
+        self.jni_env.push_local_frame(capacity)?;
+
+        // A new pool is lent to closure so local references created within the frame
+        // cannot escape from it
+        let mut frame_cp = ClassPool {
+            jni_env: unsafe { self.jni_env.unsafe_clone() },
+            class_cache: std::mem::take(&mut self.class_cache),
+            interned_names: std::mem::take(&mut self.interned_names),
+            java_names: std::mem::take(&mut self.java_names),
+            java_version: std::mem::take(&mut self.java_version),
+        };
+        // Caches are restored and the frame is popped even if closure panics
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&mut frame_cp)));
+
+        self.class_cache = frame_cp.class_cache;
+        self.interned_names = frame_cp.interned_names;
+        self.java_names = frame_cp.java_names;
+        self.java_version = frame_cp.java_version;
+
+        // SAFETY: No local reference is returned from the frame
+        let popped = unsafe { self.jni_env.pop_local_frame(&JObject::null()) };
+
+        match result {
+            Ok(result) => {
+                popped?;
+                result
+            }
+            Err(payload) => panic::resume_unwind(payload),
         }
     }
 
+    /// Gets the java version currently the jni environment is running on, the version is
+    /// cached after the first call. See [`HierExt::get_java_version`].
+    pub fn java_version(&mut self) -> Result<JavaVersion> {
+        self.java_version
+            .get_or_try_init(|| self.jni_env.get_java_version())
+            .cloned()
+    }
+
     /// Lookups a class, either from [`ClassPool`]'s internal class cache if exists, or
     /// find given class from JNI and caches.
     ///
@@ -55,15 +238,266 @@ impl<'local> ClassPool<'local> {
     /// [`lookup_class`](Self::lookup_class) uses `java.lang.Class#forName`'s class path
     /// syntax, e.g. `java.lang.Object`, instead of JNI's class path `java/lang/Object`.
     ///
-    /// # Exceptions
+    /// # Errors
     ///
-    /// If lookups a single or multiple dimension `void` type array, JVM will throws an
-    /// exception and this function will return an [`Err`].
+    /// Class path is validated before any JNI call (see [`ClassPath::validate`]), if it's
+    /// malformed, e.g. a single or multiple dimension `void` type array, this function will
+    /// return [`HierError::ClassPath`].
     pub fn lookup_class<CP>(&mut self, class_path: CP) -> Result<Class>
+    where
+        CP: IntoClassPath,
+    {
+        // Java class paths resolved before are known to be valid, and their classes can be
+        // fetched without building a `ClassPath`
+        let interned_name = class_path
+            .as_java_str()
+            .and_then(|java_cp| self.java_names.get(java_cp))
+            .cloned();
+
+        if let Some(interned_name) = interned_name {
+            return self.fetch_class(&interned_name).map(Class::new);
+        }
+
+        let class_path = class_path.into();
+
+        class_path.validate()?;
+
+        self.lookup_class_path(&class_path)
+    }
+
+    /// Lookups a class by an already built [`ClassPath`], see [`lookup_class`](Self::lookup_class).
+    /// A [`ClassPath::JNI`] is used as cache key directly without any conversion, and a
+    /// [`ClassPath::Java`] is only converted the first time it's looked up.
+    ///
+    /// Unlike [`lookup_class`](Self::lookup_class), class path is not validated, callers are
+    /// expected to [`validate`](ClassPath::validate) it beforehand.
+    pub fn lookup_class_path(&mut self, class_path: &ClassPath) -> Result<Class> {
+        match class_path {
+            ClassPath::JNI(class_path) => self.fetch_class(class_path),
+            ClassPath::Java(java_cp) => match self.java_names.get(java_cp.as_str()).cloned() {
+                Some(interned_name) => self.fetch_class(&interned_name),
+                None => {
+                    let class_path: String = class_path.convert().into();
+                    let class = self.fetch_class(&class_path)?;
+                    let interned_name = self.intern_name(&class_path);
+
+                    self.java_names.insert(java_cp.clone(), interned_name);
+
+                    Ok(class)
+                }
+            },
+        }
+        .map(Class::new)
+    }
+
+    /// Lookups a class like [`lookup_class`](Self::lookup_class), but falls back to
+    /// `java.lang.Object` if the class can't be found, like how bytecode verifiers treat
+    /// unresolvable types.
+    ///
+    /// # Errors
+    ///
+    /// Only [`HierError::ClassNotFound`] is recovered, other errors, e.g. a malformed class
+    /// path, are returned as is.
+    pub fn lookup_class_or_object<CP>(&mut self, class_path: CP) -> Result<Class>
+    where
+        CP: IntoClassPath,
+    {
+        match self.lookup_class(class_path) {
+            Err(HierError::ClassNotFound(_)) => self
+                .fetch_class(ClassInternal::OBJECT_JNI_CP)
+                .map(Class::new),
+            result => result,
+        }
+    }
+
+    /// Lookups a class like [`lookup_class`](Self::lookup_class), and ensures it's a concrete
+    /// class, i.e. not an interface, an annotation or an abstract class.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HierError::NonConcreteClassError`] if the class is rejected, see
+    /// [`lookup_concrete_class_with`](Self::lookup_concrete_class_with) to configure which
+    /// kinds of classes are rejected.
+    pub fn lookup_concrete_class<CP>(&mut self, class_path: CP) -> Result<Class>
+    where
+        CP: IntoClassPath,
+    {
+        self.lookup_concrete_class_with(class_path, ConcreteClassOptions::default())
+    }
+
+    /// Lookups a class like [`lookup_class`](Self::lookup_class), and rejects it by given
+    /// [`ConcreteClassOptions`], see [`lookup_concrete_class`](Self::lookup_concrete_class).
+    pub fn lookup_concrete_class_with<CP>(
+        &mut self,
+        class_path: CP,
+        options: ConcreteClassOptions,
+    ) -> Result<Class>
+    where
+        CP: IntoClassPath,
+    {
+        let mut class = self.lookup_class(class_path)?;
+        let rejected_as = match class.kind(self)? {
+            ClassKind::Interface if options.reject_interfaces => Some("an interface"),
+            ClassKind::Annotation if options.reject_annotations => Some("an annotation"),
+            ClassKind::Class | ClassKind::Enum | ClassKind::Record
+                if options.reject_abstract_classes
+                    && Modifiers::is_abstract_bits(class.modifiers(self)?) =>
+            {
+                Some("an abstract class")
+            }
+            _ => None,
+        };
+
+        match rejected_as {
+            Some(rejected_as) => Err(HierError::NonConcreteClassError(
+                class.name(self)?,
+                rejected_as,
+            )),
+            None => Ok(class),
+        }
+    }
+
+    /// Lookups a class through given class loader, either from [`ClassPool`]'s internal class
+    /// cache if exists, or by `java.lang.Class#forName` without initializing the class.
+    ///
+    /// Class path syntax is the same as [`lookup_class`](Self::lookup_class). Notice that the
+    /// internal class cache is keyed by class path only, so a cached class with the same class
+    /// path is only returned if it's the class given class loader resolves.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HierError::ClassLoaderMismatch`] if given class loader resolves a different
+    /// class than the cached one with the same class path, e.g. a class shadowing a system
+    /// class.
+    pub fn lookup_class_with_loader<CP>(
+        &mut self,
+        class_path: CP,
+        loader: &JObject<'_>,
+    ) -> Result<Class>
     where
         CP: Into<ClassPath>,
     {
-        let class_path: String = class_path.into().as_jni().into();
+        let class_path = class_path.into();
+
+        class_path.validate()?;
+
+        let class_path: String = class_path.as_jni().into();
+
+        if PRIMITIVE_TYPES_TO_DESC.contains_key(&class_path) {
+            return self.fetch_class(&class_path).map(Class::new);
+        }
+
+        let cached_class = self
+            .class_cache
+            .get(class_path.as_str())
+            .cloned()
+            .map(Class::new);
+
+        if let Some(cached_class) = &cached_class {
+            if cached_class.is_defined_by(self, loader)? {
+                return Ok(cached_class.clone());
+            }
+        }
+
+        let jclass = self.jni_env.with_local_frame_returning_local(2, |env| {
+            let class_name = env.new_string(class_path.replace('/', "."))?;
+
+            env.call_static_method(
+                ClassInternal::CLASS_JNI_CP,
+                "forName",
+                "(Ljava/lang/String;ZLjava/lang/ClassLoader;)Ljava/lang/Class;",
+                &[(&class_name).into(), false.into(), loader.into()],
+            )
+            .and_then(JValueGen::l)
+        });
+        let jclass = self.catch_class_not_found(jclass, &class_path)?;
+        let jclass = self.jni_env.auto_local(JClass::from(jclass));
+
+        // Class defined by an ancestor class loader is resolved through delegation
+        match cached_class {
+            Some(cached_class) if cached_class.is_same_jclass(self, &jclass)? => Ok(cached_class),
+            Some(_) => Err(HierError::ClassLoaderMismatch(class_path)),
+            None => self
+                .fetch_class_from_jclass(&jclass, Some(&class_path))
+                .map(Class::new),
+        }
+    }
+
+    /// Preloads classes through given class loader and caches them, returns the amount of
+    /// classes that were not cached before. See [`lookup_class_with_loader`](Self::lookup_class_with_loader)
+    /// for how each class is looked up.
+    pub fn preload_from_loader(
+        &mut self,
+        loader: &JObject<'_>,
+        class_names: &[&str],
+    ) -> Result<usize> {
+        let mut newly_loaded = 0;
+
+        for class_name in class_names {
+            let len = self.len();
+
+            self.lookup_class_with_loader(*class_name, loader)?;
+
+            if self.len() > len {
+                newly_loaded += 1;
+            }
+        }
+
+        Ok(newly_loaded)
+    }
+
+    /// Lookups each class by [`lookup_class`](Self::lookup_class), and partitions found classes
+    /// and failures, so a missing or unlinkable class doesn't abort the whole batch. Failures
+    /// are paired with their class names, both in the order of `class_names`.
+    ///
+    /// Pending Java exception of each failed lookup is cleared before the next lookup.
+    pub fn lookup_many_lenient(
+        &mut self,
+        class_names: &[&str],
+    ) -> (Vec<Class>, Vec<(String, HierError)>) {
+        let mut classes = Vec::with_capacity(class_names.len());
+        let mut failures = Vec::new();
+
+        for class_name in class_names {
+            match self.lookup_class(*class_name) {
+                Ok(class) => classes.push(class),
+                Err(err) => {
+                    if self.jni_env.exception_check().unwrap_or(false) {
+                        let _ = self.jni_env.exception_clear();
+                    }
+
+                    failures.push((class_name.to_string(), err));
+                }
+            }
+        }
+
+        (classes, failures)
+    }
+
+    /// Registers a [JClass] obtained elsewhere into internal class cache, so later lookups by
+    /// its class path hit the cache instead of finding it from JNI again.
+    ///
+    /// If a class with the same class path is already cached, the cached one is returned.
+    pub fn register_jclass(&mut self, jclass: &JClass<'_>) -> Result<Class> {
+        self.fetch_class_from_jclass(jclass, None).map(Class::new)
+    }
+
+    /// Lookups an array class with given element [Class] and dimensions, e.g. `int[][]`
+    /// for `int` element with 2 dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HierError::ArrayClassError`] if `dimensions` is 0 or element [Class] is
+    /// `void`.
+    pub fn lookup_array_class(&mut self, element: &Class, dimensions: usize) -> Result<Class> {
+        let element_name = element.clone().name(self)?;
+
+        if dimensions == 0 || element_name == "void" {
+            return Err(HierError::ArrayClassError(element_name, dimensions));
+        }
+
+        let element_desc = element.descriptor(self)?;
+        let class_path = format!("{}{element_desc}", "[".repeat(dimensions));
 
         self.fetch_class(&class_path).map(Class::new)
     }
@@ -78,101 +512,1612 @@ impl<'local> ClassPool<'local> {
         self.len() == 0
     }
 
-    /// Fetch an [GlobalRef] (JClass) either from cache if already fetched before, or directly
-    /// from JNI interface if not. After each successful fetching operation, [GlobalRef] (JClass)
-    /// instance will exist until the termination of program, if this is not desired,
-    /// use [free_jclass_cache] to free cache.
-    pub(crate) fn fetch_class(&mut self, class_path: &str) -> Result<Arc<Mutex<ClassInternal>>> {
-        if let Some(cached_class) = self.class_cache.get(class_path) {
-            Ok(cached_class.clone())
-        } else if PRIMITIVE_TYPES_TO_DESC.contains_key(class_path) {
-            self.fetch_primitive_class(class_path)
-        } else {
-            let jclass = self.jni_env.find_class(class_path)?;
-            self.fetch_class_from_jclass(&jclass, Some(class_path))
+    /// Gets the internal class cache's capacity.
+    pub fn capacity(&self) -> usize {
+        self.class_cache.capacity()
+    }
+
+    /// Gets class paths of all cached classes in Java form, e.g. `java.lang.Integer` and
+    /// `int[]`, sorted in lexicographical order.
+    pub fn cached_names(&self) -> Vec<String> {
+        let mut names = self
+            .class_cache
+            .keys()
+            .map(|class_path| ClassPath::JNI(class_path.to_string()).as_java().into())
+            .collect::<Vec<String>>();
+
+        names.sort();
+
+        names
+    }
+
+    /// Frees a class from internal class cache, returns true if the class was cached.
+    ///
+    /// The freed class is still usable through existing [`Class`] handles, but later
+    /// lookups will fetch it from JNI again.
+    pub fn free_class<CP>(&mut self, class_path: CP) -> bool
+    where
+        CP: Into<ClassPath>,
+    {
+        let class_path: String = class_path.into().as_jni().into();
+
+        let is_freed = self.class_cache.remove(class_path.as_str()).is_some();
+
+        self.prune_assignable_from_memos();
+        self.prune_interned_names();
+
+        is_freed
+    }
+
+    /// Frees all classes from internal class cache.
+    ///
+    /// Like [`free_class`](Self::free_class), freed classes are still usable through existing
+    /// [`Class`] handles.
+    pub fn clear(&mut self) {
+        self.prune_assignable_from_memos();
+        self.class_cache.clear();
+        self.prune_interned_names();
+    }
+
+    /// Frees all classes which are only referenced by internal class cache, i.e. there's no
+    /// outstanding [`Class`] handle of them, returns the amount of freed classes.
+    ///
+    /// Classes with live handles are kept, so their handles stay cached.
+    pub fn evict_unreachable(&mut self) -> usize {
+        let len = self.len();
+
+        self.class_cache
+            .retain(|_, class| Arc::strong_count(class) > 1);
+        self.prune_assignable_from_memos();
+        self.prune_interned_names();
+
+        len - self.len()
+    }
+
+    /// Shrinks the internal class cache's capacity as much as possible, reclaims memory
+    /// after a large amount of classes are freed.
+    pub fn shrink_to_fit(&mut self) {
+        self.class_cache.shrink_to_fit();
+        self.interned_names.shrink_to_fit();
+        self.java_names.shrink_to_fit();
+    }
+
+    /// Interns given class path, returns the shared [`Arc<str>`] so class paths used as cache
+    /// keys are allocated only once, even if the class is freed and fetched again while
+    /// handles of it are still alive.
+    pub(crate) fn intern_name(&mut self, class_path: &str) -> Arc<str> {
+        if let Some(interned_name) = self.interned_names.get(class_path) {
+            return interned_name.clone();
         }
+
+        let interned_name: Arc<str> = Arc::from(class_path);
+
+        self.interned_names.insert(interned_name.clone());
+
+        interned_name
     }
 
-    pub(crate) fn fetch_class_from_jclass(
-        &mut self,
-        jclass: &JClass<'_>,
-        known_jclass_cp: Option<&str>,
-    ) -> Result<Arc<Mutex<ClassInternal>>> {
-        match known_jclass_cp {
-            Some(cp) => self.fetch_class_from_jclass_internal(jclass, cp),
-            None => {
-                let cp = self.jni_env.with_local_frame(1, |env| {
-                    let method_id = env.get_method_id(
-                        ClassInternal::CLASS_JNI_CP,
-                        "getName",
-                        "()Ljava/lang/String;",
-                    )?;
-                    let class_name: JString = unsafe {
-                        env.call_method_unchecked(jclass, method_id, ReturnType::Object, &[])
-                            .and_then(JValueGen::l)
-                            .map(Into::into)?
-                    };
-
-                    unsafe {
-                        env.get_string_unchecked(&class_name)
-                            .map(Into::<String>::into)
-                    }
-                })?;
+    /// Drops memoized `is_assignable_from` results of cached classes against unloaded
+    /// classes, so they don't pile up.
+    fn prune_assignable_from_memos(&self) {
+        for class in self.class_cache.values() {
+            if let Ok(mut class) = class.lock() {
+                class.prune_assignable_from(&self.jni_env);
+            }
+        }
+    }
 
-                self.fetch_class_from_jclass_internal(jclass, &cp)
+    /// Drops Java class paths whose classes are no longer cached, then interned class paths
+    /// which are referenced by neither internal class cache nor any outstanding [`Class`]
+    /// handle.
+    fn prune_interned_names(&mut self) {
+        let class_cache = &self.class_cache;
+
+        self.java_names
+            .retain(|_, interned_name| class_cache.contains_key(interned_name));
+        self.interned_names
+            .retain(|interned_name| Arc::strong_count(interned_name) > 1);
+    }
+
+    /// Computes the assignability matrix among given classes, where `m[i][j]` is the result
+    /// of `classes[i].is_assignable_from(classes[j])`.
+    ///
+    /// Each class' ancestors are collected once from the cached hierarchy instead of calling
+    /// `java.lang.Class#isAssignableFrom` for every pair, only pairs of array classes fall
+    /// back to JNI since array covariance is not reflected by the hierarchy. Interfaces have
+    /// no superclass, so `java.lang.Object` is treated as assignable from every non-primitive
+    /// class.
+    pub fn assignability_matrix(&mut self, classes: &mut [Class]) -> Result<Vec<Vec<bool>>> {
+        let ancestors = classes
+            .iter()
+            .map(|class| class.all_supertypes(self))
+            .collect::<Result<Vec<_>>>()?;
+        let names = classes
+            .iter_mut()
+            .map(|class| class.name(self))
+            .collect::<Result<Vec<_>>>()?;
+        let is_array = names
+            .iter()
+            .map(|name| name.starts_with('['))
+            .collect::<Vec<_>>();
+        let mut matrix = vec![vec![false; classes.len()]; classes.len()];
+
+        for i in 0..classes.len() {
+            for j in 0..classes.len() {
+                matrix[i][j] = if Arc::ptr_eq(&classes[i], &classes[j]) {
+                    true
+                } else if names[i] == "java.lang.Object" {
+                    !PRIMITIVE_TYPES_TO_DESC.contains_key(&names[j])
+                } else if is_array[i] && is_array[j] {
+                    let other = classes[j].clone();
+                    classes[i].is_assignable_from(self, &other)?
+                } else {
+                    ancestors[j]
+                        .iter()
+                        .any(|ancestor| Arc::ptr_eq(ancestor, &classes[i]))
+                };
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Finds interfaces implemented by both given classes, see [`Class::all_interfaces`]
+    /// for which interfaces are considered. The result follows the order of `a`'s
+    /// interfaces.
+    pub fn common_interfaces(&mut self, a: &mut Class, b: &mut Class) -> Result<Vec<Class>> {
+        let b_interface_names = b
+            .all_interfaces(self)?
+            .iter_mut()
+            .map(|interface| interface.name(self))
+            .collect::<Result<HashSet<_>>>()?;
+        let mut common_interfaces = Vec::new();
+
+        for mut interface in a.all_interfaces(self)? {
+            if b_interface_names.contains(&interface.name(self)?) {
+                common_interfaces.push(interface);
             }
         }
+
+        Ok(common_interfaces)
     }
 
-    fn fetch_class_from_jclass_internal(
+    /// Walks the hierarchy of given root [Class] depth-first in pre-order, visits each class
+    /// with its depth, where root is at depth 0. Children of a class are its direct supertypes,
+    /// see [`Class::direct_supertypes`].
+    ///
+    /// Visited classes are tracked by their identities, so each class is visited once even if
+    /// it's reachable through multiple paths, e.g. shared superinterfaces. A class is visited
+    /// at the depth of the first path reaching it.
+    pub fn walk_hierarchy_dfs<F>(&mut self, root: &mut Class, mut visit: F) -> Result<()>
+    where
+        F: FnMut(&mut ClassPool<'local>, &mut Class, usize) -> Result<()>,
+    {
+        let mut visited = HashSet::new();
+        let mut pending = vec![(root.clone(), 0)];
+
+        while let Some((mut class, depth)) = pending.pop() {
+            if !visited.insert(Arc::as_ptr(&class)) {
+                continue;
+            }
+
+            visit(self, &mut class, depth)?;
+
+            let supertypes = class.direct_supertypes(self)?;
+
+            pending.extend(
+                supertypes
+                    .into_iter()
+                    .rev()
+                    .map(|supertype| (supertype, depth + 1)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Finds the most specific interface implemented by both given classes, which is the only
+    /// common interface (see [`common_interfaces`](Self::common_interfaces)) that isn't a
+    /// superinterface of any other common interface.
+    ///
+    /// Returns [None] if there's no common interface, or multiple common interfaces are
+    /// equally specific, e.g. `java.util.ArrayList` and `java.util.LinkedList` share both
+    /// `java.util.List` and `java.lang.Cloneable`.
+    pub fn most_specific_common_interface(
         &mut self,
-        jclass: &JClass<'_>,
-        known_jclass_cp: &str,
-    ) -> Result<Arc<Mutex<ClassInternal>>> {
-        let glob_ref = self.jni_env.new_global_ref(jclass)?;
-        let class = Arc::new(Mutex::new(ClassInternal::new(glob_ref)));
+        a: &mut Class,
+        b: &mut Class,
+    ) -> Result<Option<Class>> {
+        let common_interfaces = self.common_interfaces(a, b)?;
+        let mut most_specific_interfaces = Vec::new();
 
-        Ok(self
-            .class_cache
-            .entry(known_jclass_cp.to_string())
-            .or_insert(class)
-            .clone())
+        for (i, interface) in common_interfaces.iter().enumerate() {
+            let mut interface = interface.clone();
+            let mut is_most_specific = true;
+
+            for (j, other) in common_interfaces.iter().enumerate() {
+                if i != j && interface.is_assignable_from(self, other)? {
+                    is_most_specific = false;
+                    break;
+                }
+            }
+
+            if is_most_specific {
+                most_specific_interfaces.push(interface);
+            }
+        }
+
+        if most_specific_interfaces.len() == 1 {
+            Ok(most_specific_interfaces.pop())
+        } else {
+            Ok(None)
+        }
     }
 
-    fn fetch_primitive_class(&mut self, primitive_name: &str) -> Result<Arc<Mutex<ClassInternal>>> {
-        let wrapper_class_cp = PRIMITIVE_TYPES_TO_DESC
-            .get(primitive_name)
-            .and_then(|desc| DESC_TO_WRAPPER_CLASS_CP.get(desc))
-            .unwrap();
-        let static_field_id = self.jni_env.get_static_field_id(
-            wrapper_class_cp,
-            "TYPE",
-            format!("L{};", ClassInternal::CLASS_JNI_CP),
-        )?;
-        let wrapper_class: JClass = self
-            .jni_env
-            .get_static_field_unchecked(
-                wrapper_class_cp,
-                static_field_id,
-                JavaType::Object(ClassInternal::CLASS_JNI_CP.to_string()),
-            )
-            .and_then(JValueGen::l)?
-            .into();
+    /// Counts currently cached classes by their [ClassKind], classes cached during counting
+    /// (e.g. superclasses fetched to determine kinds) are not counted.
+    pub fn kind_histogram(&mut self) -> Result<HashMap<ClassKind, usize>> {
+        let classes = self.class_cache.values().cloned().collect::<Vec<_>>();
+        let mut histogram = HashMap::new();
 
-        self.fetch_class_from_jclass_internal(&wrapper_class, primitive_name)
+        for class in classes {
+            let kind = Class::new(class).kind(self)?;
+
+            *histogram.entry(kind).or_insert(0) += 1;
+        }
+
+        Ok(histogram)
     }
-}
 
-impl<'local> Deref for ClassPool<'local> {
-    type Target = JNIEnv<'local>;
+    /// Captures a [`ClassSnapshot`] of each currently cached class, sorted by class name,
+    /// classes cached during capturing (e.g. superclasses) are not captured.
+    #[cfg(feature = "serde")]
+    pub fn snapshot_all(&mut self) -> Result<Vec<ClassSnapshot>> {
+        let classes = self.class_cache.values().cloned().collect::<Vec<_>>();
+        let mut snapshots = classes
+            .into_iter()
+            .map(|class| ClassSnapshot::capture(self, &mut Class::new(class)))
+            .collect::<Result<Vec<_>>>()?;
 
-    fn deref(&self) -> &Self::Target {
-        &self.jni_env
+        snapshots.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(snapshots)
     }
-}
 
-impl<'local> DerefMut for ClassPool<'local> {
+    /// Boxes given primitive value into its wrapper object, e.g. `int` into
+    /// `java.lang.Integer`, by calling wrapper class' `valueOf`.
+    ///
+    /// # Errors
+    ///
+    /// If `prim` is not a primitive type other than `void`, or the value's type doesn't match
+    /// `prim`, [`HierError::BoxValueError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let int_class = cp.lookup_class("int")?;
+    /// let integer = cp.box_value(&int_class, JValue::Int(5))?;
+    /// ```
+    pub fn box_value(&mut self, prim: &Class, value: JValue<'_, '_>) -> Result<GlobalRef> {
+        let desc = prim.descriptor(self)?;
+        let wrapper_class_cp = DESC_TO_WRAPPER_CLASS_CP
+            .get(&desc)
+            .filter(|_| desc != "V")
+            .filter(|_| value.primitive_type().map(|ty| ty.to_string()).as_ref() == Some(&desc));
+
+        let Some(wrapper_class_cp) = wrapper_class_cp else {
+            return Err(HierError::BoxValueError(
+                prim.clone().name(self)?,
+                value.type_name(),
+            ));
+        };
+
+        let boxed_value = self
+            .jni_env
+            .call_static_method(
+                wrapper_class_cp,
+                "valueOf",
+                format!("({desc})L{wrapper_class_cp};"),
+                &[value],
+            )
+            .and_then(JValueGen::l)?;
+        let boxed_value = self.jni_env.auto_local(boxed_value);
+
+        self.jni_env
+            .new_global_ref(&*boxed_value)
+            .map_err(Into::into)
+    }
+
+    /// Unboxes given primitive wrapper object into its primitive value, e.g.
+    /// `java.lang.Integer` into `int`, by calling wrapper class' `xxxValue`.
+    ///
+    /// # Errors
+    ///
+    /// If the object is not an instance of primitive wrapper types (`java.lang.Void`
+    /// excluded), [`HierError::UnboxValueError`] is returned.
+    pub fn unbox_value(&mut self, obj: &JObject<'_>) -> Result<JValueOwned<'local>> {
+        let class_name = self.class_of(obj)?.name(self)?;
+        let desc = DESC_TO_WRAPPER_CLASS_CP
+            .entries()
+            .find(|(desc, wrapper_class_cp)| {
+                **desc != "V" && wrapper_class_cp.replace('/', ".") == class_name
+            })
+            .map(|(desc, _)| *desc);
+        let prim_name = desc.and_then(|desc| {
+            PRIMITIVE_TYPES_TO_DESC
+                .entries()
+                .find(|(_, prim_desc)| **prim_desc == desc)
+                .map(|(prim_name, _)| *prim_name)
+        });
+
+        let (Some(desc), Some(prim_name)) = (desc, prim_name) else {
+            return Err(HierError::UnboxValueError(class_name));
+        };
+
+        self.jni_env
+            .call_method(obj, format!("{prim_name}Value"), format!("(){desc}"), &[])
+            .map_err(Into::into)
+    }
+
+    /// Returns the runtime [Class] of given object, the cached [Class] is returned if the
+    /// class was looked up before, e.g. by [`lookup_class`](Self::lookup_class).
+    ///
+    /// This function is equivalent to `java.lang.Object#getClass`.
+    pub fn class_of(&mut self, obj: &JObject<'_>) -> Result<Class> {
+        let class = self.jni_env.get_object_class(obj)?;
+        let class = self.jni_env.auto_local(class);
+
+        self.fetch_class_from_jclass(&class, None).map(Class::new)
+    }
+
+    /// Determines if two objects are equal in Java semantic rather than identity, e.g. two
+    /// distinct `java.lang.Integer` instances with the same value are equal. See
+    /// [`is_same_object`](JNIEnv::is_same_object) for identity comparison.
+    ///
+    /// This function is equivalent to `java.util.Objects#equals`, which means both objects
+    /// can be `null`. Exceptions thrown by `equals` are returned as [`HierError::JavaException`].
+    pub fn objects_equal(&mut self, a: &JObject<'_>, b: &JObject<'_>) -> Result<bool> {
+        let result = self
+            .jni_env
+            .call_static_method(
+                "java/util/Objects",
+                "equals",
+                "(Ljava/lang/Object;Ljava/lang/Object;)Z",
+                &[a.into(), b.into()],
+            )
+            .and_then(JValueGen::z);
+
+        self.catch_java_exception(result)
+    }
+
+    /// Returns class names of annotation types present on given annotated element, e.g.
+    /// `java.lang.reflect.Method` and `java.lang.reflect.Field`.
+    pub(crate) fn annotation_type_names(
+        &mut self,
+        annotated_element: &JObject<'_>,
+    ) -> Result<Vec<String>> {
+        self.jni_env.with_local_frame(4, |env| {
+            let annotation_arr: JObjectArray = env
+                .call_method(
+                    annotated_element,
+                    "getAnnotations",
+                    "()[Ljava/lang/annotation/Annotation;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?
+                .into();
+            let annotations_len = env.get_array_length(&annotation_arr)?;
+            let mut annotation_type_names = Vec::with_capacity(annotations_len as usize);
+
+            for i in 0..annotations_len {
+                let annotation = env.get_object_array_element(&annotation_arr, i)?;
+                let annotation = env.auto_local(annotation);
+                let annotation_type = env
+                    .call_method(&annotation, "annotationType", "()Ljava/lang/Class;", &[])
+                    .and_then(JValueGen::l)?;
+                let annotation_type = env.auto_local(JClass::from(annotation_type));
+
+                annotation_type_names.push(env.class_name(&*annotation_type)?);
+            }
+
+            Ok(annotation_type_names)
+        })
+    }
+
+    /// Converts given result into [HierError::JavaException] if it's failed due to a pending
+    /// java exception, the exception is cleared afterward.
+    pub(crate) fn catch_java_exception<T>(&mut self, result: jni::errors::Result<T>) -> Result<T> {
+        if !matches!(result, Err(jni::errors::Error::JavaException)) {
+            return result.map_err(Into::into);
+        }
+
+        match self.jni_env.describe_pending_exception(true)? {
+            Some((class_name, message)) => Err(HierError::JavaException(class_name, message)),
+            None => result.map_err(Into::into),
+        }
+    }
+
+    /// Converts pending `java.lang.ClassNotFoundException` or `java.lang.NoClassDefFoundError`
+    /// into [`HierError::ClassNotFound`] with given class path and clears it, other results are
+    /// returned as is.
+    fn catch_class_not_found<T>(
+        &mut self,
+        result: jni::errors::Result<T>,
+        class_path: &str,
+    ) -> Result<T> {
+        if !matches!(result, Err(jni::errors::Error::JavaException)) {
+            return result.map_err(Into::into);
+        }
+
+        match self.jni_env.describe_pending_exception(false)? {
+            Some((class_name, _))
+                if class_name == "java.lang.ClassNotFoundException"
+                    || class_name == "java.lang.NoClassDefFoundError" =>
+            {
+                self.jni_env.exception_clear()?;
+
+                let class_path: String = ClassPath::JNI(class_path.to_string()).as_java().into();
+
+                Err(HierError::ClassNotFound(class_path))
+            }
+            _ => result.map_err(Into::into),
+        }
+    }
+
+    /// Fetch an [GlobalRef] (JClass) either from cache if already fetched before, or directly
+    /// from JNI interface if not. After each successful fetching operation, [GlobalRef] (JClass)
+    /// instance will exist until the termination of program, if this is not desired,
+    /// use [free_jclass_cache] to free cache.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip(self),
+            fields(cache_hit = tracing::field::Empty)
+        )
+    )]
+    pub(crate) fn fetch_class(&mut self, class_path: &str) -> Result<Arc<Mutex<ClassInternal>>> {
+        let cached_class = self.class_cache.get(class_path);
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("cache_hit", cached_class.is_some());
+
+        if let Some(cached_class) = cached_class {
+            Ok(cached_class.clone())
+        } else if PRIMITIVE_TYPES_TO_DESC.contains_key(class_path) {
+            self.fetch_primitive_class(class_path)
+        } else {
+            let jclass = self.jni_env.find_class(class_path);
+            let jclass = self.catch_class_not_found(jclass, class_path)?;
+            self.fetch_class_from_jclass(&jclass, Some(class_path))
+        }
+    }
+
+    pub(crate) fn fetch_class_from_jclass(
+        &mut self,
+        jclass: &JClass<'_>,
+        known_jclass_cp: Option<&str>,
+    ) -> Result<Arc<Mutex<ClassInternal>>> {
+        match known_jclass_cp {
+            Some(cp) => self.fetch_class_from_jclass_internal(jclass, cp),
+            None => {
+                // `HierExt::class_name` returns `java.lang.Class#getName`'s form which uses
+                // dots as separators, while the cache is keyed by JNI class paths.
+                let cp = self.jni_env.class_name(jclass)?.replace('.', "/");
+
+                self.fetch_class_from_jclass_internal(jclass, &cp)
+            }
+        }
+    }
+
+    fn fetch_class_from_jclass_internal(
+        &mut self,
+        jclass: &JClass<'_>,
+        known_jclass_cp: &str,
+    ) -> Result<Arc<Mutex<ClassInternal>>> {
+        if let Some(cached_class) = self.class_cache.get(known_jclass_cp) {
+            return Ok(cached_class.clone());
+        }
+
+        let glob_ref = self.jni_env.new_global_ref(jclass)?;
+        let class_path = self.intern_name(known_jclass_cp);
+        let class = Arc::new(Mutex::new(ClassInternal::new(glob_ref, class_path.clone())));
+
+        Ok(self.class_cache.entry(class_path).or_insert(class).clone())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    fn fetch_primitive_class(&mut self, primitive_name: &str) -> Result<Arc<Mutex<ClassInternal>>> {
+        let wrapper_class_cp = PRIMITIVE_TYPES_TO_DESC
+            .get(primitive_name)
+            .and_then(|desc| DESC_TO_WRAPPER_CLASS_CP.get(desc))
+            .unwrap();
+        let static_field_id = self.jni_env.get_static_field_id(
+            wrapper_class_cp,
+            "TYPE",
+            format!("L{};", ClassInternal::CLASS_JNI_CP),
+        )?;
+        let wrapper_class: JClass = self
+            .jni_env
+            .get_static_field_unchecked(
+                wrapper_class_cp,
+                static_field_id,
+                JavaType::Object(ClassInternal::CLASS_JNI_CP.to_string()),
+            )
+            .and_then(JValueGen::l)?
+            .into();
+
+        self.fetch_class_from_jclass_internal(&wrapper_class, primitive_name)
+    }
+}
+
+#[cfg(feature = "invocation")]
+impl ClassPool<'static> {
+    /// Gets the process-wide [`ClassPool`], which is constructed by
+    /// [`from_permanent_env`](Self::from_permanent_env) on first use, this is an opt-in
+    /// convenience for simple applications that only use a single JVM.
+    ///
+    /// # Locking
+    ///
+    /// The pool is guarded by a [`Mutex`], the returned guard must be dropped before calling
+    /// `global` again on the same thread, otherwise it deadlocks. Other threads are blocked
+    /// until the guard is dropped, consider constructing dedicated pools for concurrent works.
+    /// Current thread is attached to the JVM permanently each time the pool is locked.
+    pub fn global() -> Result<std::sync::MutexGuard<'static, GlobalClassPool>> {
+        use crate::java_vm::jvm;
+
+        static GLOBAL_CLASS_POOL: OnceCell<Mutex<GlobalClassPool>> = OnceCell::new();
+
+        let mut cp = GLOBAL_CLASS_POOL
+            .get_or_try_init(|| {
+                Self::from_permanent_env().map(|cp| Mutex::new(GlobalClassPool(cp)))
+            })?
+            .lock()?;
+
+        cp.0.reattach(jvm()?)?;
+
+        Ok(cp)
+    }
+}
+
+/// The process-wide [`ClassPool`] returned by [`ClassPool::global`].
+#[cfg(feature = "invocation")]
+#[derive(Debug)]
+pub struct GlobalClassPool(ClassPool<'static>);
+
+// SAFETY: The JNIEnv is replaced with current thread's one each time the pool is locked by
+// ClassPool::global, and the guard cannot be sent to other threads.
+#[cfg(feature = "invocation")]
+unsafe impl Send for GlobalClassPool {}
+
+#[cfg(feature = "invocation")]
+impl Deref for GlobalClassPool {
+    type Target = ClassPool<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "invocation")]
+impl DerefMut for GlobalClassPool {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A [`ClassPool`] which detaches current thread when dropped if the thread was attached by
+/// [`ClassPool::from_vm_scoped`].
+pub struct ScopedClassPool<'local> {
+    // Declared before the guard so the pool is dropped before current thread is detached
+    cp: ClassPool<'local>,
+    _guard: AttachGuard<'local>,
+}
+
+impl Debug for ScopedClassPool<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ScopedClassPool").field(&self.cp).finish()
+    }
+}
+
+impl<'local> Deref for ScopedClassPool<'local> {
+    type Target = ClassPool<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cp
+    }
+}
+
+impl<'local> DerefMut for ScopedClassPool<'local> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cp
+    }
+}
+
+/// Options to control which kinds of classes are rejected by
+/// [`ClassPool::lookup_concrete_class_with`], all kinds are rejected by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcreteClassOptions {
+    /// Rejects interfaces, annotations are controlled by
+    /// [`reject_annotations`](Self::reject_annotations) instead.
+    pub reject_interfaces: bool,
+    /// Rejects annotations.
+    pub reject_annotations: bool,
+    /// Rejects abstract classes, including enums with constant-specific class bodies
+    /// which declare abstract methods.
+    pub reject_abstract_classes: bool,
+}
+
+impl Default for ConcreteClassOptions {
+    fn default() -> Self {
+        Self {
+            reject_interfaces: true,
+            reject_annotations: true,
+            reject_abstract_classes: true,
+        }
+    }
+}
+
+impl Debug for ClassPool<'_> {
+    /// Formats [ClassPool] without calling into JNI, cached class paths are sorted and
+    /// truncated after [`ClassPool::DEBUG_CLASS_PATHS_LIMIT`] entries.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct CachedClassPaths<'a>(&'a ClassCache);
+
+        impl Debug for CachedClassPaths<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut class_paths = self.0.keys().collect::<Vec<_>>();
+
+                class_paths.sort();
+
+                let mut list = f.debug_list();
+
+                list.entries(class_paths.iter().take(ClassPool::DEBUG_CLASS_PATHS_LIMIT));
+
+                if class_paths.len() > ClassPool::DEBUG_CLASS_PATHS_LIMIT {
+                    list.entry(&format_args!(
+                        "... {} more",
+                        class_paths.len() - ClassPool::DEBUG_CLASS_PATHS_LIMIT
+                    ));
+                }
+
+                list.finish()
+            }
+        }
+
+        f.debug_struct("ClassPool")
+            .field("jni_env", &self.jni_env.get_raw())
+            .field("len", &self.len())
+            .field("class_cache", &CachedClassPaths(&self.class_cache))
+            .finish()
+    }
+}
+
+impl<'local> Deref for ClassPool<'local> {
+    type Target = JNIEnv<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.jni_env
+    }
+}
+
+impl<'local> DerefMut for ClassPool<'local> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.jni_env
     }
 }
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use std::{
+        collections::HashMap,
+        panic::{self, AssertUnwindSafe},
+        sync::Arc,
+    };
+
+    use jni::objects::{JObject, JValue};
+    use rstest::rstest;
+
+    use crate::{
+        class::ClassKind,
+        classpath::ClassPath,
+        classpool::{ClassPool, ConcreteClassOptions},
+        errors::{ClassPathError, HierError, HierResult},
+        fixtures::{fixture_class_loader, lookup_fixture, new_fixture_class_loader},
+        java_vm::jvm,
+    };
+
+    #[test]
+    fn test_fetch_class_from_jclass_cache_key() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let superclass = class
+            .superclass(&mut cp)?
+            .expect("java.lang.Integer should have superclass");
+        let number_class = cp.lookup_class("java.lang.Number")?;
+
+        assert!(Arc::ptr_eq(&superclass, &number_class));
+        assert_eq!(cp.len(), 2);
+
+        let integer = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(5)])?;
+        let integer_class = cp.class_of(&integer)?;
+
+        assert!(Arc::ptr_eq(&integer_class, &class));
+        assert_eq!(cp.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assignability_matrix() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut classes = [
+            cp.lookup_class("java.lang.Object")?,
+            cp.lookup_class("java.lang.Number")?,
+            cp.lookup_class("java.lang.Integer")?,
+            cp.lookup_class("java.util.List")?,
+            cp.lookup_class("java.util.ArrayList")?,
+            cp.lookup_class("java.lang.Integer[]")?,
+            cp.lookup_class("java.lang.Number[]")?,
+            cp.lookup_class("int")?,
+        ];
+        let matrix = cp.assignability_matrix(&mut classes)?;
+
+        assert_eq!(
+            matrix,
+            vec![
+                vec![true, true, true, true, true, true, true, false],
+                vec![false, true, true, false, false, false, false, false],
+                vec![false, false, true, false, false, false, false, false],
+                vec![false, false, false, true, true, false, false, false],
+                vec![false, false, false, false, true, false, false, false],
+                vec![false, false, false, false, false, true, false, false],
+                vec![false, false, false, false, false, true, true, false],
+                vec![false, false, false, false, false, false, false, true],
+            ]
+        );
+
+        // Matrix agrees with `is_assignable_from` on every pair
+        for i in 0..classes.len() {
+            for j in 0..classes.len() {
+                let other = classes[j].clone();
+
+                assert_eq!(
+                    matrix[i][j],
+                    classes[i].is_assignable_from(&mut cp, &other)?
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_common_interfaces() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut integer_class = cp.lookup_class("java.lang.Integer")?;
+        let mut long_class = cp.lookup_class("java.lang.Long")?;
+        let common_interface_names = cp
+            .common_interfaces(&mut integer_class, &mut long_class)?
+            .iter_mut()
+            .map(|interface| interface.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert_eq!(common_interface_names[0], "java.lang.Comparable");
+        assert!(common_interface_names.contains(&"java.io.Serializable".to_string()));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        "java.util.AbstractList",
+        "java.util.AbstractSet",
+        Some("java.util.Collection")
+    )]
+    #[case(
+        "java.util.ArrayList",
+        "java.lang.Integer",
+        Some("java.io.Serializable")
+    )]
+    #[case("java.util.ArrayList", "java.util.LinkedList", None)]
+    #[case("java.util.ArrayList", "java.lang.Object", None)]
+    fn test_most_specific_common_interface(
+        #[case] a: &'static str,
+        #[case] b: &'static str,
+        #[case] expected: Option<&'static str>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut a = cp.lookup_class(a)?;
+        let mut b = cp.lookup_class(b)?;
+        let interface_name = cp
+            .most_specific_common_interface(&mut a, &mut b)?
+            .map(|mut interface| interface.name(&mut cp))
+            .transpose()?;
+
+        assert_eq!(interface_name.as_deref(), expected);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("int", 1, "[I")]
+    #[case("int", 2, "[[I")]
+    #[case("java.lang.String", 1, "[Ljava.lang.String;")]
+    #[case("java.lang.String[]", 1, "[[Ljava.lang.String;")]
+    fn test_lookup_array_class(
+        #[case] element: &'static str,
+        #[case] dimensions: usize,
+        #[case] get_name_result: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let element = cp.lookup_class(element)?;
+        let mut array_class = cp.lookup_array_class(&element, dimensions)?;
+
+        assert_eq!(array_class.name(&mut cp)?, get_name_result);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("void", 1)]
+    #[case("int", 0)]
+    fn test_invalid_array_class(
+        #[case] element: &'static str,
+        #[case] dimensions: usize,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let element = cp.lookup_class(element)?;
+
+        assert!(matches!(
+            cp.lookup_array_class(&element, dimensions),
+            Err(HierError::ArrayClassError(_, _))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        cp.lookup_class("java.lang.Integer")?;
+        cp.lookup_class("java.lang.Float")?;
+
+        let debug = format!("{cp:?}");
+
+        assert!(debug.contains(r#"len: 2"#));
+        assert!(debug.contains(r#"["java/lang/Float", "java/lang/Integer"]"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preload_from_loader() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let urls = cp.new_object_array(0, "java/net/URL", JObject::null())?;
+        let loader = cp.new_object(
+            "java/net/URLClassLoader",
+            "([Ljava/net/URL;)V",
+            &[(&urls).into()],
+        )?;
+        let class_names = ["java.util.ArrayList", "java.util.HashMap"];
+
+        assert_eq!(cp.preload_from_loader(&loader, &class_names)?, 2);
+        assert_eq!(cp.preload_from_loader(&loader, &class_names)?, 0);
+        assert_eq!(cp.len(), 2);
+
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+
+        assert_eq!(class.name(&mut cp)?, "java.util.ArrayList");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_class_with_loader_mismatch() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let marker = lookup_fixture(&mut cp, "fixtures.Marker")?;
+        let loader = fixture_class_loader(&mut cp)?;
+        let cached_marker = cp.lookup_class_with_loader("fixtures.Marker", loader.as_obj())?;
+
+        assert!(Arc::ptr_eq(&cached_marker, &marker));
+
+        // Another class loader defines its own `fixtures.Marker`
+        let other_loader = new_fixture_class_loader(&mut cp)?;
+
+        assert!(matches!(
+            cp.lookup_class_with_loader("fixtures.Marker", other_loader.as_obj()),
+            Err(HierError::ClassLoaderMismatch(class_path)) if class_path == "fixtures/Marker"
+        ));
+        assert!(matches!(
+            cp.preload_from_loader(other_loader.as_obj(), &["fixtures.Marker"]),
+            Err(HierError::ClassLoaderMismatch(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detach_current_thread() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        cp.lookup_class("java.lang.Object")?;
+
+        unsafe {
+            cp.detach_current_thread()?;
+        }
+
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Object")?;
+
+        assert_eq!(class.name(&mut cp)?, "java.lang.Object");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reattach() -> HierResult<()> {
+        struct MovedClassPool(ClassPool<'static>);
+
+        // SAFETY: the pool is reattached to the new thread before its env is used.
+        unsafe impl Send for MovedClassPool {}
+
+        impl MovedClassPool {
+            fn into_inner(self) -> ClassPool<'static> {
+                self.0
+            }
+        }
+
+        let mut cp = ClassPool::from_permanent_env()?;
+        let class = cp.lookup_class("java.lang.Object")?;
+        let moved_cp = MovedClassPool(cp);
+
+        std::thread::spawn(move || -> HierResult<()> {
+            let mut cp = moved_cp.into_inner();
+
+            cp.reattach(jvm()?)?;
+
+            let mut reattached_class = cp.lookup_class("java.lang.Object")?;
+
+            assert_eq!(cp.len(), 1);
+            assert!(Arc::ptr_eq(&class, &reattached_class));
+            assert_eq!(reattached_class.name(&mut cp)?, "java.lang.Object");
+
+            Ok(())
+        })
+        .join()
+        .expect("reattached thread panicked")
+    }
+
+    #[test]
+    fn test_from_vm_scoped() -> HierResult<()> {
+        std::thread::spawn(|| -> HierResult<()> {
+            let vm = jvm()?;
+
+            assert!(vm.get_env().is_err());
+
+            {
+                let mut cp = ClassPool::from_vm_scoped(vm)?;
+                let mut class = cp.lookup_class("java.lang.Integer")?;
+
+                assert_eq!(class.name(&mut cp)?, "java.lang.Integer");
+                assert!(vm.get_env().is_ok());
+            }
+
+            assert!(vm.get_env().is_err());
+
+            let mut guard = vm.attach_current_thread()?;
+
+            assert!(guard.find_class("java/lang/Integer").is_ok());
+
+            Ok(())
+        })
+        .join()
+        .expect("scoped thread panicked")?;
+
+        std::thread::spawn(|| -> HierResult<()> {
+            let vm = jvm()?;
+            let _cp = ClassPool::from_permanent_env()?;
+
+            drop(ClassPool::from_vm_scoped(vm)?);
+
+            assert!(vm.get_env().is_ok());
+
+            Ok(())
+        })
+        .join()
+        .expect("permanently attached thread panicked")
+    }
+
+    #[test]
+    fn test_with_frame() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        for _ in 0..1000 {
+            cp.with_frame(16, |cp| {
+                for _ in 0..64 {
+                    cp.clear();
+                    cp.lookup_class("java.lang.String")?;
+                    cp.find_class("java/lang/Object")?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        let mut class = cp.lookup_class("java.lang.String")?;
+
+        assert_eq!(cp.len(), 1);
+        assert_eq!(class.name(&mut cp)?, "java.lang.String");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_frame_panic() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let class = cp.lookup_class("java.lang.String")?;
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            cp.with_frame(16, |cp| -> HierResult<()> {
+                cp.lookup_class("java.lang.Integer")?;
+                panic!("closure panicked");
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(cp.len(), 2);
+
+        let cached_class = cp.lookup_class("java.lang.String")?;
+
+        assert!(Arc::ptr_eq(&cached_class, &class));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kind_histogram() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        for class_path in [
+            "java.lang.String",
+            "java.lang.Integer",
+            "java.lang.Comparable",
+            "java.lang.Override",
+            "java.lang.Thread$State",
+            "int",
+            "int[]",
+        ] {
+            cp.lookup_class(class_path)?;
+        }
+
+        let histogram = cp.kind_histogram()?;
+
+        assert_eq!(
+            histogram,
+            HashMap::from([
+                (ClassKind::Class, 2),
+                (ClassKind::Interface, 1),
+                (ClassKind::Annotation, 1),
+                (ClassKind::Enum, 1),
+                (ClassKind::Primitive, 1),
+                (ClassKind::Array, 1),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_global() -> HierResult<()> {
+        let class = ClassPool::global()?.lookup_class("java.lang.Object")?;
+
+        let global_class = std::thread::spawn(|| -> HierResult<_> {
+            let mut cp = ClassPool::global()?;
+            let mut class = cp.lookup_class("java.lang.Object")?;
+
+            assert_eq!(class.name(&mut cp)?, "java.lang.Object");
+
+            Ok(class)
+        })
+        .join()
+        .expect("global pool thread panicked")?;
+
+        assert!(Arc::ptr_eq(&class, &global_class));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_class_path() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let class_path = ClassPath::JNI("java/util/Map$Entry".to_string());
+
+        class_path.validate()?;
+
+        let class = cp.lookup_class_path(&class_path)?;
+        let cached_class = cp.lookup_class("java.util.Map$Entry")?;
+        let mut java_class = cp.lookup_class_path(&class_path.convert())?;
+
+        assert_eq!(cp.len(), 1);
+        assert!(Arc::ptr_eq(&class, &cached_class));
+        assert!(Arc::ptr_eq(&class, &java_class));
+        assert_eq!(java_class.name(&mut cp)?, "java.util.Map$Entry");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_box_value() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let int_class = cp.lookup_class("int")?;
+        let boxed_value = cp.box_value(&int_class, JValue::Int(5))?;
+
+        assert!(cp.is_instance_of(&boxed_value, "java/lang/Integer")?);
+        assert_eq!(
+            cp.call_method(&boxed_value, "intValue", "()I", &[])?.i()?,
+            5
+        );
+
+        let string_class = cp.lookup_class("java.lang.String")?;
+
+        assert!(matches!(
+            cp.box_value(&int_class, JValue::Long(5)),
+            Err(HierError::BoxValueError(_, "long"))
+        ));
+        assert!(matches!(
+            cp.box_value(&string_class, JValue::Int(5)),
+            Err(HierError::BoxValueError(_, "int"))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unbox_value() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let integer = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(42)])?;
+
+        assert_eq!(cp.unbox_value(&integer)?.i()?, 42);
+
+        let char_class = cp.lookup_class("char")?;
+        let boxed_value = cp.box_value(&char_class, JValue::Char('c' as u16))?;
+
+        assert_eq!(cp.unbox_value(&boxed_value)?.c()?, 'c' as u16);
+
+        let string = cp.new_string("42")?;
+
+        assert!(matches!(
+            cp.unbox_value(&string),
+            Err(HierError::UnboxValueError(class_name)) if class_name == "java.lang.String"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_of_round_trip() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut integer_class = cp.lookup_class("java.lang.Integer")?;
+        let integer = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(5)])?;
+        let cache_len = cp.len();
+        let mut class = cp.class_of(&integer)?;
+
+        assert_eq!(cp.len(), cache_len);
+        assert!(class.is_same_class(&mut cp, &integer_class)?);
+        assert!(class.is_instance_of_cached(&mut cp, &integer)?);
+        assert!(integer_class.is_instance_of_cached(&mut cp, &integer)?);
+        assert!(cp
+            .lookup_class("java.lang.Number")?
+            .is_instance_of_cached(&mut cp, &integer)?);
+        assert!(!cp
+            .lookup_class("java.lang.String")?
+            .is_instance_of_cached(&mut cp, &integer)?);
+        assert!(!class.is_instance_of_cached(&mut cp, &JObject::null())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_objects_equal() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let a = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(5)])?;
+        let b = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(5)])?;
+        let c = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(6)])?;
+
+        assert!(cp.objects_equal(&a, &b)?);
+        assert!(!cp.is_same_object(&a, &b)?);
+        assert!(!cp.objects_equal(&a, &c)?);
+        assert!(!cp.objects_equal(&a, &JObject::null())?);
+        assert!(cp.objects_equal(&JObject::null(), &JObject::null())?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn test_tracing_spans() -> HierResult<()> {
+        use std::{
+            fmt::{Debug, Write},
+            sync::Mutex,
+        };
+
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Subscriber,
+        };
+        use tracing_subscriber::{
+            layer::{Context, SubscriberExt},
+            registry::LookupSpan,
+            Layer,
+        };
+
+        #[derive(Default, Clone)]
+        struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+
+        impl SpanRecorder {
+            fn push(&self, name: &str, visit: impl FnOnce(&mut dyn Visit)) {
+                let mut fields = String::new();
+                let mut visitor = |field: &Field, value: &dyn Debug| {
+                    write!(fields, " {field}={value:?}").unwrap();
+                };
+
+                visit(&mut visitor);
+                self.0.lock().unwrap().push(format!("{name}:{fields}"));
+            }
+        }
+
+        impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanRecorder {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+                self.push(attrs.metadata().name(), |visitor| attrs.record(visitor));
+            }
+
+            fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+                let name = ctx.span(id).map(|span| span.name()).unwrap_or_default();
+
+                self.push(name, |visitor| values.record(visitor));
+            }
+        }
+
+        let recorder = SpanRecorder::default();
+        let subscriber = tracing_subscriber::registry().with(recorder.clone());
+
+        tracing::subscriber::with_default(subscriber, || -> HierResult<()> {
+            let mut cp = ClassPool::from_permanent_env()?;
+
+            cp.lookup_class("java.lang.Object")?;
+            cp.lookup_class("java.lang.Object")?;
+            cp.lookup_class("int")?;
+
+            Ok(())
+        })?;
+
+        let spans = recorder.0.lock().unwrap().clone();
+
+        assert_eq!(
+            spans,
+            [
+                r#"fetch_class: class_path="java/lang/Object""#,
+                "fetch_class: cache_hit=false",
+                r#"fetch_class: class_path="java/lang/Object""#,
+                "fetch_class: cache_hit=true",
+                r#"fetch_class: class_path="int""#,
+                "fetch_class: cache_hit=false",
+                r#"fetch_primitive_class: primitive_name="int""#,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_jclass() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let jclass = cp.find_class("java/util/Map$Entry")?;
+        let registered_class = cp.register_jclass(&jclass)?;
+        let class = cp.lookup_class("java.util.Map$Entry")?;
+
+        assert_eq!(cp.len(), 1);
+        assert!(Arc::ptr_eq(&registered_class, &class));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_malformed_class() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert!(matches!(
+            cp.lookup_class("a..b"),
+            Err(HierError::ClassPath(ClassPathError::IllegalCharacter(
+                _,
+                '.'
+            )))
+        ));
+        assert!(matches!(
+            cp.lookup_class("void[]"),
+            Err(HierError::ClassPath(ClassPathError::VoidArray(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_concrete_class() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert!(matches!(
+            cp.lookup_concrete_class("java.util.List"),
+            Err(HierError::NonConcreteClassError(_, "an interface"))
+        ));
+        assert!(matches!(
+            cp.lookup_concrete_class("java.lang.Deprecated"),
+            Err(HierError::NonConcreteClassError(_, "an annotation"))
+        ));
+        assert!(matches!(
+            cp.lookup_concrete_class("java.util.AbstractList"),
+            Err(HierError::NonConcreteClassError(_, "an abstract class"))
+        ));
+        assert_eq!(
+            cp.lookup_concrete_class("java.lang.String")?
+                .name(&mut cp)?,
+            "java.lang.String"
+        );
+
+        let options = ConcreteClassOptions {
+            reject_abstract_classes: false,
+            ..Default::default()
+        };
+
+        assert!(cp
+            .lookup_concrete_class_with("java.util.AbstractList", options)
+            .is_ok());
+        assert!(cp
+            .lookup_concrete_class_with("java.util.List", options)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_many_lenient() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let (mut classes, failures) = cp.lookup_many_lenient(&[
+            "java.lang.String",
+            "com.example.Missing",
+            "java.util.List",
+            "void[]",
+        ]);
+        let class_names = classes
+            .iter_mut()
+            .map(|class| class.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert_eq!(class_names, ["java.lang.String", "java.util.List"]);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].0, "com.example.Missing");
+        assert_eq!(failures[1].0, "void[]");
+        assert!(matches!(
+            failures[1].1,
+            HierError::ClassPath(ClassPathError::VoidArray(_))
+        ));
+        assert!(!cp.exception_check()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_not_found() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert!(matches!(
+            cp.lookup_class("does.not.Exist"),
+            Err(HierError::ClassNotFound(class_path)) if class_path == "does.not.Exist"
+        ));
+        assert!(!cp.exception_check()?);
+
+        let loader = fixture_class_loader(&mut cp)?;
+
+        assert!(matches!(
+            cp.lookup_class_with_loader("does.not.Exist", loader.as_obj()),
+            Err(HierError::ClassNotFound(class_path)) if class_path == "does.not.Exist"
+        ));
+        assert!(!cp.exception_check()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intern_name() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let _class = cp.lookup_class("java.lang.String")?;
+        let _class = cp.lookup_class("java.lang.String")?;
+        let (cached_name, _) = cp
+            .class_cache
+            .get_key_value("java/lang/String")
+            .expect("java.lang.String should be cached");
+        let cached_name = cached_name.clone();
+
+        assert!(Arc::ptr_eq(
+            &cached_name,
+            &cp.intern_name("java/lang/String")
+        ));
+        assert_eq!(cp.interned_names.len(), cp.len());
+
+        // Class handle keeps interned class path alive after it's freed
+        assert!(cp.free_class("java.lang.String"));
+        assert!(cp.java_names.is_empty());
+        let _class = cp.lookup_class("java.lang.String")?;
+
+        assert!(Arc::ptr_eq(
+            &cached_name,
+            &cp.intern_name("java/lang/String")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_walk_hierarchy_dfs() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+        let mut visited = Vec::new();
+
+        cp.walk_hierarchy_dfs(&mut class, |cp, class, depth| {
+            visited.push((class.name(cp)?, depth));
+            Ok(())
+        })?;
+
+        let mut visited_names = visited
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(visited[0], ("java.util.ArrayList".to_string(), 0));
+        assert_eq!(visited[1], ("java.util.AbstractList".to_string(), 1));
+        assert!(visited.contains(&("java.util.Collection".to_string(), 3)));
+
+        let visited_len = visited_names.len();
+
+        visited_names.sort();
+        visited_names.dedup();
+
+        assert_eq!(visited_names.len(), visited_len);
+        assert!(visited_names.contains(&"java.lang.Iterable"));
+        assert!(visited_names.contains(&"java.lang.Object"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_permanent_env_checked() -> HierResult<()> {
+        // Shared JVM instance is always initialized with `-Xcheck:jni` in unit tests
+        ClassPool::from_permanent_env()?;
+
+        let mut cp = ClassPool::from_permanent_env_checked(true)?;
+
+        assert_eq!(
+            cp.lookup_class("java.lang.Integer")?.name(&mut cp)?,
+            "java.lang.Integer"
+        );
+        assert!(matches!(
+            ClassPool::from_permanent_env_checked(false),
+            Err(HierError::JvmAlreadyInitialized)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cached_names() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert!(cp.cached_names().is_empty());
+
+        cp.lookup_class("java.lang.Integer")?;
+        cp.lookup_class("java.lang.Float")?;
+        cp.lookup_class("int[][]")?;
+
+        assert_eq!(
+            cp.cached_names(),
+            ["int[][]", "java.lang.Float", "java.lang.Integer"]
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("does.not.Exist", "java.lang.Object")]
+    #[case("java.lang.String", "java.lang.String")]
+    fn test_lookup_class_or_object(
+        #[case] class_path: &'static str,
+        #[case] class_name: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert_eq!(
+            cp.lookup_class_or_object(class_path)?.name(&mut cp)?,
+            class_name
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_class_or_object_error() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert!(matches!(
+            cp.lookup_class_or_object("void[]"),
+            Err(HierError::ClassPath(ClassPathError::VoidArray(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_evict_unreachable() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let retained_class = cp.lookup_class("java.lang.String")?;
+        let mut dropped_class = cp.lookup_class("java.lang.Integer")?;
+
+        // Superclass is cached as well, but no handle is retained
+        dropped_class.superclass(&mut cp)?;
+        drop(dropped_class);
+
+        assert_eq!(cp.len(), 3);
+        assert_eq!(cp.evict_unreachable(), 2);
+        assert_eq!(cp.len(), 1);
+
+        let class = cp.lookup_class("java.lang.String")?;
+
+        assert!(Arc::ptr_eq(&retained_class, &class));
+        assert_eq!(cp.evict_unreachable(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink_to_fit() -> HierResult<()> {
+        const CLASSES: [&str; 16] = [
+            "java.lang.Object",
+            "java.lang.String",
+            "java.lang.Integer",
+            "java.lang.Long",
+            "java.lang.Short",
+            "java.lang.Byte",
+            "java.lang.Float",
+            "java.lang.Double",
+            "java.lang.Character",
+            "java.lang.Boolean",
+            "java.lang.Number",
+            "java.lang.Math",
+            "java.lang.Thread",
+            "java.lang.Runnable",
+            "java.lang.Comparable",
+            "java.lang.Iterable",
+        ];
+
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        for class in CLASSES {
+            cp.lookup_class(class)?;
+        }
+
+        let capacity = cp.capacity();
+
+        for class in &CLASSES[1..] {
+            assert!(cp.free_class(*class));
+        }
+
+        cp.shrink_to_fit();
+
+        assert_eq!(cp.len(), 1);
+        assert!(cp.capacity() < capacity / 2);
+
+        Ok(())
+    }
+}
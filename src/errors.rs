@@ -15,12 +15,49 @@ pub enum HierError {
     #[error(transparent)]
     #[cfg(feature = "invocation")]
     StartJvmError(#[from] jni::errors::StartJvmError),
+    #[error("JVM is already initialized, only one JVM can exist in a process")]
+    #[cfg(feature = "invocation")]
+    JvmAlreadyInitialized,
     #[error(transparent)]
     JniError(#[from] JniError),
     #[error("unable to access to class cache, reason: {0}")]
     CacheAccessError(&'static str),
+    #[error("unable to find the class {0}")]
+    ClassNotFound(String),
     #[error("unable to find the class {0} in the cache, Class probably had been freed up")]
     DanglingClassError(String),
+    #[error("class {0} is already cached from another class loader")]
+    ClassLoaderMismatch(String),
+    #[error("unable to construct {1} dimension(s) array class of {0}")]
+    ArrayClassError(String, usize),
+    #[error(transparent)]
+    ClassPath(#[from] ClassPathError),
+    #[error("class {0} is not a concrete class, it is {1}")]
+    NonConcreteClassError(String, &'static str),
+    #[error("unable to box value of type {1} as {0}")]
+    BoxValueError(String, &'static str),
+    #[error("unable to unbox object of type {0}, which is not a primitive wrapper type")]
+    UnboxValueError(String),
+    #[error("argument {0} is not an instance of class {1}")]
+    ArgumentTypeMismatch(usize, String),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    Utf8Error(#[from] std::string::FromUtf8Error),
+    #[error("java exception {0} is thrown{}", .1.as_ref().map(|message| format!(": {message}")).unwrap_or_default())]
+    JavaException(String, Option<String>),
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClassPathError {
+    #[error("class path is empty")]
+    EmptyPath,
+    #[error("class path {0} contains illegal character {1:?}")]
+    IllegalCharacter(String, char),
+    #[error("class path {0} represents an array of void")]
+    VoidArray(String),
+    #[error("class path {0} is not a valid array descriptor")]
+    BadDescriptor(String),
 }
 
 impl<T> From<PoisonError<T>> for HierError {
@@ -0,0 +1,81 @@
+//! Java fixture classes used by tests, sources under `tests/fixtures` are compiled by `javac`
+//! on first use and loaded through a dedicated `java.net.URLClassLoader`.
+
+use std::{fs, path::PathBuf, process::Command};
+
+use jni::objects::{GlobalRef, JValueGen};
+use once_cell::sync::OnceCell;
+
+use crate::{class::Class, classpool::ClassPool, errors::HierResult as Result};
+
+const FIXTURES_SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Compiles fixture sources into a temporary directory, returns the directory.
+fn compile_fixtures() -> &'static PathBuf {
+    static FIXTURES_DIR: OnceCell<PathBuf> = OnceCell::new();
+
+    FIXTURES_DIR.get_or_init(|| {
+        let fixtures_dir =
+            std::env::temp_dir().join(format!("hier-fixtures-{}", std::process::id()));
+        let sources = fs::read_dir(FIXTURES_SRC_DIR)
+            .expect("unable to read fixture sources")
+            .map(|entry| entry.expect("unable to read fixture source").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "java"))
+            .collect::<Vec<_>>();
+        let status = Command::new("javac")
+            .arg("-d")
+            .arg(&fixtures_dir)
+            .args(sources)
+            .status()
+            .expect("unable to run javac, JDK is required to compile fixtures");
+
+        assert!(status.success(), "unable to compile fixtures");
+
+        fixtures_dir
+    })
+}
+
+/// Gets the class loader which loads fixture classes, the same class loader is shared
+/// across tests so fixture classes are only loaded once.
+pub(crate) fn fixture_class_loader(cp: &mut ClassPool<'_>) -> Result<&'static GlobalRef> {
+    static FIXTURE_CLASS_LOADER: OnceCell<GlobalRef> = OnceCell::new();
+
+    FIXTURE_CLASS_LOADER.get_or_try_init(|| new_fixture_class_loader(cp))
+}
+
+/// Creates a new class loader which loads fixture classes, classes loaded by it are
+/// distinct from ones loaded by [fixture_class_loader] despite having the same names.
+pub(crate) fn new_fixture_class_loader(cp: &mut ClassPool<'_>) -> Result<GlobalRef> {
+    let fixtures_dir = compile_fixtures().to_string_lossy().into_owned();
+    let loader = cp.with_local_frame_returning_local(4, |env| {
+        let fixtures_dir = env.new_string(fixtures_dir)?;
+        let fixtures_dir = env.new_object(
+            "java/io/File",
+            "(Ljava/lang/String;)V",
+            &[(&fixtures_dir).into()],
+        )?;
+        let fixtures_uri = env
+            .call_method(&fixtures_dir, "toURI", "()Ljava/net/URI;", &[])
+            .and_then(JValueGen::l)?;
+        let fixtures_url = env
+            .call_method(&fixtures_uri, "toURL", "()Ljava/net/URL;", &[])
+            .and_then(JValueGen::l)?;
+        let urls = env.new_object_array(1, "java/net/URL", fixtures_url)?;
+
+        env.new_object(
+            "java/net/URLClassLoader",
+            "([Ljava/net/URL;)V",
+            &[(&urls).into()],
+        )
+    })?;
+    let loader = cp.auto_local(loader);
+
+    cp.new_global_ref(&*loader).map_err(Into::into)
+}
+
+/// Lookups a fixture class, e.g. `fixtures.Marker`.
+pub(crate) fn lookup_fixture(cp: &mut ClassPool<'_>, class_path: &str) -> Result<Class> {
+    let loader = fixture_class_loader(cp)?;
+
+    cp.lookup_class_with_loader(class_path, loader.as_obj())
+}
@@ -1 +1,392 @@
+use std::{collections::HashSet, io::Write};
 
+use graphviz_rust::{
+    dot_generator::*,
+    dot_structures::*,
+    printer::{DotPrinter, PrinterContext},
+};
+
+use crate::{class::Class, classpool::ClassPool, errors::HierResult as Result};
+
+/// Generates class hierarchy tree of given [Class] in DOT language, each edge points from a
+/// class to either its superclass or one of its interfaces.
+///
+/// If given [Class] is an interface, the tree consists of its extended interfaces
+/// recursively, since interfaces have no superclass.
+///
+/// # Example
+///
+/// ```rs
+/// let mut cp = ClassPool::from_permanent_env()?;
+/// let mut class = cp.lookup_class("java.lang.Integer")?;
+/// let dot = generate_class_hierarchy_tree(&mut cp, &mut class)?;
+///
+/// println!("{dot}");
+/// ```
+pub fn generate_class_hierarchy_tree(cp: &mut ClassPool<'_>, class: &mut Class) -> Result<String> {
+    generate_class_hierarchy_tree_with(cp, class, HierarchyOptions::default())
+}
+
+/// Writes class hierarchy tree of given [Class] in DOT language into given writer, see
+/// [generate_class_hierarchy_tree]. DOT statements are written one by one, so the whole DOT
+/// is never held in memory, e.g. when streaming a large graph into a file.
+///
+/// # Example
+///
+/// ```rs
+/// let mut cp = ClassPool::from_permanent_env()?;
+/// let mut class = cp.lookup_class("java.lang.Integer")?;
+/// let mut file = File::create("hierarchy.dot")?;
+///
+/// write_class_hierarchy_tree(&mut cp, &mut class, &mut file)?;
+/// ```
+pub fn write_class_hierarchy_tree<W>(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    out: &mut W,
+) -> Result<()>
+where
+    W: Write,
+{
+    write_graph(cp, class, HierarchyOptions::default(), |_| true, out)
+}
+
+/// Collects edges of class hierarchy tree of given [Class], see
+/// [generate_class_hierarchy_tree]. Edges are in depth-first order, each class contributes
+/// its edges once.
+///
+/// # Example
+///
+/// ```rs
+/// let mut cp = ClassPool::from_permanent_env()?;
+/// let mut class = cp.lookup_class("java.lang.Integer")?;
+///
+/// for edge in class_hierarchy_edges(&mut cp, &mut class)? {
+///     println!("{} -> {} ({:?})", edge.from, edge.to, edge.kind);
+/// }
+/// ```
+pub fn class_hierarchy_edges(cp: &mut ClassPool<'_>, class: &mut Class) -> Result<Vec<Edge>> {
+    let mut edges = Vec::new();
+
+    collect_edges(
+        cp,
+        class,
+        &HierarchyOptions::default(),
+        &mut edges,
+        &mut HashSet::new(),
+    )?;
+
+    Ok(edges)
+}
+
+/// An edge of class hierarchy tree, points from a class to one of its direct supertypes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// Name of the subtype.
+    pub from: String,
+    /// Name of the supertype.
+    pub to: String,
+    /// Relationship between both types.
+    pub kind: EdgeKind,
+}
+
+/// Relationship kind of an [Edge].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// From a class to its superclass. Edges from root interfaces to `java.lang.Object`,
+    /// see [HierarchyOptions::interfaces_including_object], are also of this kind.
+    Extends,
+    /// From a class to one of its interfaces.
+    Implements,
+    /// From an interface to one of its extended interfaces.
+    ExtendsInterface,
+}
+
+/// Options to control how class hierarchy tree is collected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HierarchyOptions {
+    /// Links every root interface (an interface which extends no other interfaces) to
+    /// `java.lang.Object`, so merged class and interface hierarchies are connected.
+    ///
+    /// Disabled by default, which matches JVM's behavior that interfaces have no superclass.
+    pub interfaces_including_object: bool,
+}
+
+/// Generates class hierarchy tree of given [Class] in DOT language with given
+/// [HierarchyOptions], see [generate_class_hierarchy_tree].
+pub fn generate_class_hierarchy_tree_with(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    options: HierarchyOptions,
+) -> Result<String> {
+    generate_graph(cp, class, options, |_| true)
+}
+
+/// Generates class hierarchy tree of given [Class] in DOT language, see
+/// [generate_class_hierarchy_tree]. A class node is emitted only if its class name passes
+/// given filter, edges from or to excluded classes are dropped.
+///
+/// # Example
+///
+/// ```rs
+/// let mut cp = ClassPool::from_permanent_env()?;
+/// let mut class = cp.lookup_class("com.myapp.Main")?;
+/// let dot = generate_class_hierarchy_tree_filtered(&mut cp, &mut class, |name| {
+///     name.starts_with("com.myapp.")
+/// })?;
+/// ```
+pub fn generate_class_hierarchy_tree_filtered<F>(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    include: F,
+) -> Result<String>
+where
+    F: Fn(&str) -> bool,
+{
+    generate_graph(cp, class, HierarchyOptions::default(), include)
+}
+
+fn generate_graph<F>(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    options: HierarchyOptions,
+    include: F,
+) -> Result<String>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut dot = Vec::new();
+
+    write_graph(cp, class, options, include, &mut dot)?;
+
+    String::from_utf8(dot).map_err(Into::into)
+}
+
+fn write_graph<F, W>(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    options: HierarchyOptions,
+    include: F,
+    out: &mut W,
+) -> Result<()>
+where
+    F: Fn(&str) -> bool,
+    W: Write,
+{
+    let mut edges = Vec::new();
+
+    collect_edges(cp, class, &options, &mut edges, &mut HashSet::new())?;
+
+    // Statements are written one by one instead of printing a whole graphviz `Graph`, so
+    // the DOT is never held in memory entirely
+    let mut ctx = PrinterContext::default();
+    let class_name = class.name(cp)?;
+
+    writeln!(out, "digraph class_hierarchy {{")?;
+
+    if include(&class_name) {
+        writeln!(out, "    {}", node_id!(esc class_name).print(&mut ctx))?;
+    }
+
+    for hierarchy_edge in edges {
+        let (from, to) = (hierarchy_edge.from, hierarchy_edge.to);
+
+        if include(&from) && include(&to) {
+            writeln!(
+                out,
+                "    {} -> {}",
+                node_id!(esc from).print(&mut ctx),
+                node_id!(esc to).print(&mut ctx)
+            )?;
+        }
+    }
+
+    write!(out, "}}").map_err(Into::into)
+}
+
+/// Collects edges from given [Class] to its superclass and interfaces, then recursively
+/// collects edges from them. Visited classes are skipped so shared supertypes only
+/// contribute their edges once.
+fn collect_edges(
+    cp: &mut ClassPool<'_>,
+    class: &mut Class,
+    options: &HierarchyOptions,
+    edges: &mut Vec<Edge>,
+    visited: &mut HashSet<String>,
+) -> Result<()> {
+    let class_name = class.name(cp)?;
+
+    if !visited.insert(class_name.clone()) {
+        return Ok(());
+    }
+
+    let supertypes = class.direct_supertypes(cp)?;
+    let is_interface = class.is_interface(cp)?;
+
+    if options.interfaces_including_object && supertypes.is_empty() && is_interface {
+        edges.push(Edge {
+            from: class_name.clone(),
+            to: "java.lang.Object".to_string(),
+            kind: EdgeKind::Extends,
+        });
+    }
+
+    for mut supertype in supertypes {
+        let kind = if is_interface {
+            EdgeKind::ExtendsInterface
+        } else if supertype.is_interface(cp)? {
+            EdgeKind::Implements
+        } else {
+            EdgeKind::Extends
+        };
+
+        edges.push(Edge {
+            from: class_name.clone(),
+            to: supertype.name(cp)?,
+            kind,
+        });
+        collect_edges(cp, &mut supertype, options, edges, visited)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{
+        classpool::ClassPool,
+        errors::HierResult,
+        fixtures::lookup_fixture,
+        graph::{
+            class_hierarchy_edges, generate_class_hierarchy_tree,
+            generate_class_hierarchy_tree_filtered, generate_class_hierarchy_tree_with,
+            write_class_hierarchy_tree, Edge, EdgeKind, HierarchyOptions,
+        },
+    };
+
+    #[test]
+    fn test_interface_hierarchy_tree() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.List")?;
+        let dot = generate_class_hierarchy_tree(&mut cp, &mut class)?;
+
+        assert!(dot.contains(r#""java.util.Collection" -> "java.lang.Iterable""#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interfaces_including_object() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+        let iterable_to_object = r#""java.lang.Iterable" -> "java.lang.Object""#;
+        let random_access_to_object = r#""java.util.RandomAccess" -> "java.lang.Object""#;
+
+        let dot = generate_class_hierarchy_tree(&mut cp, &mut class)?;
+
+        assert!(!dot.contains(iterable_to_object));
+        assert!(!dot.contains(random_access_to_object));
+
+        let dot = generate_class_hierarchy_tree_with(
+            &mut cp,
+            &mut class,
+            HierarchyOptions {
+                interfaces_including_object: true,
+            },
+        )?;
+
+        assert!(dot.contains(iterable_to_object));
+        assert!(dot.contains(random_access_to_object));
+        assert!(!dot.contains(r#""java.util.List" -> "java.lang.Object""#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filtered_hierarchy_tree() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+        let dot = generate_class_hierarchy_tree_filtered(&mut cp, &mut class, |name| {
+            !name.starts_with("java.lang.")
+        })?;
+
+        assert!(dot.contains(r#""java.util.ArrayList" -> "java.util.AbstractList""#));
+        assert!(!dot.contains("java.lang."));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_hierarchy_tree() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let dot = generate_class_hierarchy_tree(&mut cp, &mut class)?;
+
+        let mut buf = Vec::new();
+        write_class_hierarchy_tree(&mut cp, &mut class, &mut buf)?;
+
+        assert_eq!(String::from_utf8(buf)?, dot);
+
+        let mut cursor = Cursor::new(Vec::new());
+        write_class_hierarchy_tree(&mut cp, &mut class, &mut cursor)?;
+
+        assert_eq!(String::from_utf8(cursor.into_inner())?, dot);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hierarchy_tree_format() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, "fixtures.DeprecatedClass")?;
+        let dot = generate_class_hierarchy_tree(&mut cp, &mut class)?;
+
+        assert_eq!(
+            dot,
+            concat!(
+                "digraph class_hierarchy {\n",
+                "    \"fixtures.DeprecatedClass\"\n",
+                "    \"fixtures.DeprecatedClass\" -> \"java.lang.Object\"\n",
+                "}"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_kinds() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let edges = class_hierarchy_edges(&mut cp, &mut class)?;
+        let edge = |from: &str, to: &str, kind| Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+        };
+
+        assert!(edges.contains(&edge(
+            "java.lang.Integer",
+            "java.lang.Number",
+            EdgeKind::Extends
+        )));
+        assert!(edges.contains(&edge(
+            "java.lang.Integer",
+            "java.lang.Comparable",
+            EdgeKind::Implements
+        )));
+
+        let mut class = cp.lookup_class("java.util.List")?;
+        let edges = class_hierarchy_edges(&mut cp, &mut class)?;
+
+        assert!(edges.contains(&edge(
+            "java.util.List",
+            "java.util.Collection",
+            EdgeKind::ExtendsInterface
+        )));
+
+        Ok(())
+    }
+}
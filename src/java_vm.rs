@@ -1,27 +1,89 @@
 use std::sync::Arc;
 
-use jni::{InitArgsBuilder, JNIEnv, JNIVersion, JavaVM};
+use jni::{InitArgs, InitArgsBuilder, JNIEnv, JNIVersion, JavaVM};
 use once_cell::sync::OnceCell;
 
-use crate::errors::HierResult as Result;
+use crate::errors::{HierError, HierResult as Result};
 
-/// Get JVM instance, initialize if does not exist.
-fn jvm() -> Result<&'static Arc<JavaVM>> {
-    static JVM: OnceCell<Arc<JavaVM>> = OnceCell::new();
+static JVM: OnceCell<Arc<JavaVM>> = OnceCell::new();
+/// Whether `-Xcheck:jni` was enabled when JVM instance was initialized, stays unset if JVM
+/// instance was initialized with custom arguments by [jni_env_with].
+static CHECK_JNI: OnceCell<bool> = OnceCell::new();
 
-    JVM.get_or_try_init(|| -> Result<Arc<JavaVM>> {
-        let jvm_args = InitArgsBuilder::new()
-            .version(JNIVersion::V8)
-            .option("-Xcheck:jni")
-            .build()?;
+/// Get JVM instance, initialize with `-Xcheck:jni` if does not exist.
+pub(crate) fn jvm() -> Result<&'static Arc<JavaVM>> {
+    JVM.get_or_try_init(|| init_jvm(true))
+}
+
+/// Get JVM instance, initialize if does not exist, `-Xcheck:jni` is only enabled if
+/// `check_jni` is set.
+///
+/// If JVM instance is already initialized with a different or unknown `-Xcheck:jni`
+/// setting, an [HierError::JvmAlreadyInitialized] is returned.
+fn jvm_checked(check_jni: bool) -> Result<&'static Arc<JavaVM>> {
+    let jvm = JVM.get_or_try_init(|| init_jvm(check_jni))?;
+
+    if CHECK_JNI.get() != Some(&check_jni) {
+        return Err(HierError::JvmAlreadyInitialized);
+    }
+
+    Ok(jvm)
+}
+
+fn init_jvm(check_jni: bool) -> Result<Arc<JavaVM>> {
+    let mut jvm_args = InitArgsBuilder::new().version(JNIVersion::V8);
+
+    if check_jni {
+        jvm_args = jvm_args.option("-Xcheck:jni");
+    }
+
+    let jvm = JavaVM::new(jvm_args.build()?)?;
+    let _ = CHECK_JNI.set(check_jni);
 
-        let jvm = JavaVM::new(jvm_args)?;
+    Ok(Arc::new(jvm))
+}
+
+/// Initialize JVM instance with given arguments and get JNI environment instance, notice
+/// that the thread is attached permanently.
+///
+/// Only one JVM instance can exist in a process, if it's already initialized, an
+/// [HierError::JvmAlreadyInitialized] is returned.
+pub fn jni_env_with(jvm_args: InitArgs) -> Result<JNIEnv<'static>> {
+    let mut jvm_args = Some(jvm_args);
+    let jvm = JVM.get_or_try_init(|| -> Result<Arc<JavaVM>> {
+        let jvm = JavaVM::new(jvm_args.take().unwrap())?;
 
         Ok(Arc::new(jvm))
-    })
+    })?;
+
+    if jvm_args.is_some() {
+        return Err(HierError::JvmAlreadyInitialized);
+    }
+
+    jvm.attach_current_thread_permanently().map_err(Into::into)
 }
 
 /// Get JNI environment instance, notice that the thread is attached permanently.
 pub fn jni_env() -> Result<JNIEnv<'static>> {
     jvm().and_then(|jvm| jvm.attach_current_thread_permanently().map_err(Into::into))
 }
+
+/// Get JNI environment instance like [jni_env], but JVM instance is initialized with
+/// `-Xcheck:jni` only if `check_jni` is set, notice that the thread is attached permanently.
+///
+/// If JVM instance is already initialized with a different `-Xcheck:jni` setting, or with
+/// custom arguments by [jni_env_with], an [HierError::JvmAlreadyInitialized] is returned.
+pub fn jni_env_checked(check_jni: bool) -> Result<JNIEnv<'static>> {
+    jvm_checked(check_jni)
+        .and_then(|jvm| jvm.attach_current_thread_permanently().map_err(Into::into))
+}
+
+/// Detaches current thread from JVM instance, detaching a non-attached thread is a no-op.
+///
+/// # Safety
+///
+/// All [`JNIEnv`]s attached to current thread are invalidated after detaching, see
+/// [`JavaVM::detach_current_thread`].
+pub unsafe fn detach_current_thread() -> Result<()> {
+    jvm().map(|jvm| jvm.detach_current_thread())
+}
@@ -1,29 +1,37 @@
 #![doc = include_str!("../README.md")]
 
-use std::ops::Deref;
+use std::{collections::HashMap, ops::Deref};
 
 use class::ClassInternal;
 
 use errors::HierResult as Result;
 use jni::{
     descriptors::Desc,
-    objects::{JClass, JValueGen},
+    objects::{GlobalRef, JClass, JObjectArray, JString, JValueGen},
     signature::ReturnType,
     JNIEnv,
 };
 use version::JavaVersion;
 
+pub mod args;
+mod classfile;
 pub mod classpath;
 pub mod classpool;
 pub mod errors;
+#[cfg(all(test, feature = "invocation"))]
+mod fixtures;
 #[cfg(feature = "graph")]
 pub mod graph;
 #[cfg(feature = "invocation")]
 mod java_vm;
+#[cfg(feature = "serde")]
+pub mod snapshot;
 pub mod version;
 
 mod model {
     pub mod class;
+    pub mod field;
+    pub mod method;
     pub mod modifiers;
 }
 
@@ -38,7 +46,35 @@ pub trait HierExt<'local> {
     /// Gets the java version currently the jni environment is running on.
     fn get_java_version(&mut self) -> Result<JavaVersion>;
 
-    /// Returns the given class' class path.
+    /// Gets the full runtime version, e.g. `17.0.2+8-86`, which includes update and build
+    /// information the specification version lacks.
+    ///
+    /// This function is equivalent to `java.lang.Runtime.version().toString()` on Java 9
+    /// and later, and falls back to `java.version` system property on Java 8.
+    fn runtime_version(&mut self) -> Result<String>;
+
+    /// Gets all system properties, which is equivalent to `java.lang.System#getProperties`.
+    fn system_properties(&mut self) -> Result<HashMap<String, String>>;
+
+    /// Gets the context class loader of current thread, returns [None] if it's not set.
+    ///
+    /// This function is equivalent to `java.lang.Thread.currentThread().getContextClassLoader()`,
+    /// the result can be used in [`ClassPool::lookup_class_with_loader`](classpool::ClassPool::lookup_class_with_loader)
+    /// to resolve classes the way application code would.
+    fn context_class_loader(&mut self) -> Result<Option<GlobalRef>>;
+
+    /// Describes the pending Java exception by its class name and `java.lang.Throwable#getMessage`,
+    /// returns [None] if no exception is pending.
+    ///
+    /// The exception stays pending after the call unless `clear` is set. If `getMessage` itself
+    /// throws, that exception is discarded and message is [None].
+    fn describe_pending_exception(
+        &mut self,
+        clear: bool,
+    ) -> Result<Option<(String, Option<String>)>>;
+
+    /// Returns the given class' name in `java.lang.Class#getName`'s form, e.g.
+    /// `java.lang.Object` and `[Ljava.lang.String;`, which is the same as [`Class::name`](class::Class::name).
     fn class_name<'other_local, T>(&mut self, class: T) -> Result<String>
     where
         T: Desc<'local, JClass<'other_local>>;
@@ -65,6 +101,160 @@ impl<'local> HierExt<'local> for JNIEnv<'local> {
         }
     }
 
+    fn runtime_version(&mut self) -> Result<String> {
+        let supports_runtime_version = self.get_java_version()?.supports_modules();
+
+        self.with_local_frame(2, |env| {
+            let version: JString = if supports_runtime_version {
+                let version = env
+                    .call_static_method(
+                        "java/lang/Runtime",
+                        "version",
+                        "()Ljava/lang/Runtime$Version;",
+                        &[],
+                    )
+                    .and_then(JValueGen::l)?;
+
+                env.call_method(&version, "toString", "()Ljava/lang/String;", &[])
+                    .and_then(JValueGen::l)?
+                    .into()
+            } else {
+                let property = env.new_string("java.version")?;
+
+                env.call_static_method(
+                    "java/lang/System",
+                    "getProperty",
+                    "(Ljava/lang/String;)Ljava/lang/String;",
+                    &[(&property).into()],
+                )
+                .and_then(JValueGen::l)?
+                .into()
+            };
+
+            env.get_string(&version).map(Into::into).map_err(Into::into)
+        })
+    }
+
+    fn system_properties(&mut self) -> Result<HashMap<String, String>> {
+        self.with_local_frame(3, |env| {
+            let properties = env
+                .call_static_method(
+                    "java/lang/System",
+                    "getProperties",
+                    "()Ljava/util/Properties;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?;
+            let property_names = env
+                .call_method(&properties, "stringPropertyNames", "()Ljava/util/Set;", &[])
+                .and_then(JValueGen::l)?;
+            let property_names: JObjectArray = env
+                .call_method(&property_names, "toArray", "()[Ljava/lang/Object;", &[])
+                .and_then(JValueGen::l)?
+                .into();
+            let properties_len = env.get_array_length(&property_names)?;
+            let mut system_properties = HashMap::with_capacity(properties_len as usize);
+
+            for i in 0..properties_len {
+                let property_name: JString =
+                    env.get_object_array_element(&property_names, i)?.into();
+                let property_name = env.auto_local(property_name);
+                let property_value: JString = env
+                    .call_method(
+                        &properties,
+                        "getProperty",
+                        "(Ljava/lang/String;)Ljava/lang/String;",
+                        &[(&property_name).into()],
+                    )
+                    .and_then(JValueGen::l)?
+                    .into();
+                let property_value = env.auto_local(property_value);
+
+                // Property might be removed concurrently after names are collected
+                if property_value.is_null() {
+                    continue;
+                }
+
+                system_properties.insert(
+                    env.get_string(&property_name)?.into(),
+                    env.get_string(&property_value)?.into(),
+                );
+            }
+
+            Ok(system_properties)
+        })
+    }
+
+    fn context_class_loader(&mut self) -> Result<Option<GlobalRef>> {
+        self.with_local_frame(2, |env| {
+            let thread = env
+                .call_static_method(
+                    "java/lang/Thread",
+                    "currentThread",
+                    "()Ljava/lang/Thread;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?;
+            let class_loader = env
+                .call_method(
+                    &thread,
+                    "getContextClassLoader",
+                    "()Ljava/lang/ClassLoader;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?;
+
+            if class_loader.is_null() {
+                return Ok(None);
+            }
+
+            env.new_global_ref(class_loader)
+                .map(Some)
+                .map_err(Into::into)
+        })
+    }
+
+    fn describe_pending_exception(
+        &mut self,
+        clear: bool,
+    ) -> Result<Option<(String, Option<String>)>> {
+        if !self.exception_check()? {
+            return Ok(None);
+        }
+
+        // JNI functions can't be called while an exception is pending, so the exception is
+        // cleared first and re-thrown afterwards if requested
+        let throwable = self.exception_occurred()?;
+        let throwable = self.auto_local(throwable);
+
+        self.exception_clear()?;
+
+        let description = self.with_local_frame(2, |env| -> Result<_> {
+            let throwable_class = env.get_object_class(&*throwable)?;
+            let class_name = env.class_name(&throwable_class)?;
+            let message = match env
+                .call_method(&*throwable, "getMessage", "()Ljava/lang/String;", &[])
+                .and_then(JValueGen::l)
+            {
+                Ok(message) if message.is_null() => None,
+                Ok(message) => Some(env.get_string(&message.into())?.into()),
+                Err(jni::errors::Error::JavaException) => {
+                    env.exception_clear()?;
+                    None
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            Ok((class_name, message))
+        });
+
+        if !clear {
+            self.throw(&*throwable)?;
+        }
+
+        description.map(Some)
+    }
+
     fn class_name<'other_local, T>(&mut self, class: T) -> Result<String>
     where
         T: Desc<'local, JClass<'other_local>>,
@@ -88,3 +278,97 @@ impl<'local> HierExt<'local> for JNIEnv<'local> {
         }
     }
 }
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use rstest::rstest;
+
+    use crate::{classpath::ClassPath, classpool::ClassPool, errors::HierResult, HierExt};
+
+    #[test]
+    fn test_system_properties() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let system_properties = cp.system_properties()?;
+
+        assert!(system_properties.contains_key("java.specification.version"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_version() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let runtime_version = cp.runtime_version()?;
+        let system_properties = cp.system_properties()?;
+
+        assert!(!runtime_version.is_empty());
+
+        if cp.get_java_version()?.supports_modules() {
+            assert!(runtime_version.starts_with(&system_properties["java.specification.version"]));
+        } else {
+            assert_eq!(runtime_version, system_properties["java.version"]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_context_class_loader() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        if let Some(class_loader) = cp.context_class_loader()? {
+            let mut class = cp.lookup_class_with_loader("java.util.ArrayList", &class_loader)?;
+
+            assert_eq!(class.name(&mut cp)?, "java.util.ArrayList");
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Object", "java.lang.Object")]
+    #[case("java.util.Map$Entry", "java.util.Map$Entry")]
+    #[case("java.lang.String[]", "[Ljava.lang.String;")]
+    fn test_class_name_format(
+        #[case] class_path: &'static str,
+        #[case] class_name: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let jclass = cp.find_class(String::from(ClassPath::from(class_path).as_jni()))?;
+
+        assert_eq!(cp.class_name(&jclass)?, class_name);
+        assert_eq!(class.name(&mut cp)?, class_name);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_pending_exception() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert_eq!(cp.describe_pending_exception(false)?, None);
+
+        let number = cp.new_string("not a number")?;
+        let result = cp.call_static_method(
+            "java/lang/Integer",
+            "parseInt",
+            "(Ljava/lang/String;)I",
+            &[(&number).into()],
+        );
+
+        assert!(result.is_err());
+
+        let description = Some((
+            "java.lang.NumberFormatException".to_string(),
+            Some(r#"For input string: "not a number""#.to_string()),
+        ));
+
+        assert_eq!(cp.describe_pending_exception(false)?, description);
+        assert!(cp.exception_check()?);
+        assert_eq!(cp.describe_pending_exception(true)?, description);
+        assert!(!cp.exception_check()?);
+
+        Ok(())
+    }
+}
@@ -1,14 +1,45 @@
-use std::fmt::{Display, Pointer};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 use std::ops::Deref;
-use std::sync::{Arc, Mutex, Weak};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
 
-use jni::objects::{GlobalRef, JObject, JObjectArray, JString, JValue, JValueGen, JValueOwned};
+use jni::objects::{
+    GlobalRef, JClass, JObject, JObjectArray, JString, JValue, JValueGen, JValueOwned, WeakRef,
+};
 use jni::signature::{Primitive, ReturnType};
+use jni::JNIEnv;
 use once_cell::sync::OnceCell;
 
+use crate::classfile;
+use crate::classpath::{ClassPath, DESC_TO_WRAPPER_CLASS_CP, PRIMITIVE_TYPES_TO_DESC};
 use crate::classpool::ClassPool;
 use crate::errors::HierResult as Result;
+use crate::field::Field;
+use crate::method::Method;
 use crate::modifiers::Modifiers;
+use crate::HierExt;
+
+/// The kind of type a [Class] represents, see [`Class::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClassKind {
+    Class,
+    Interface,
+    Annotation,
+    Enum,
+    Record,
+    Array,
+    Primitive,
+}
+
+/// A lightweight summary of a class, which is not cached by [ClassPool], see
+/// [`Class::interface_summaries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassSummary {
+    /// Class name in `java.lang.Class#getName`'s form.
+    pub name: String,
+    /// Class access flags, see [Modifiers].
+    pub modifiers: u16,
+}
 
 /// A rust side pseudo class that projects java side `java.lang.Class`, used for simplify
 /// class property lookup and other class-related operations.
@@ -19,6 +50,10 @@ use crate::modifiers::Modifiers;
 /// guaranteed for later operations, and should be update by fetching the latest one (See
 /// [`HierExt::lookup_class`](crate::HierExt::lookup_class)). Operations after cache being
 /// freed are considered undefined behavior.
+///
+/// Like `java.lang.Class`, a [Class] always represents a raw type, e.g. both `List<String>`
+/// and `List<Integer>` are represented by `java.util.List`, type parameters declared by the
+/// raw type can be inspected by [`type_parameter_names`](Class::type_parameter_names).
 #[derive(Clone)]
 pub struct Class {
     inner: Arc<Mutex<ClassInternal>>,
@@ -52,6 +87,171 @@ impl Class {
             .map(|opt_superclass| opt_superclass.map(Self::new))
     }
 
+    /// Fetches the name of current [Class]'s direct superclass, see [`superclass`](Self::superclass)
+    /// and [`name`](Self::name), returns [None] for if current [Class] is `Class(java.lang.Object)`
+    /// or an interface.
+    pub fn superclass_name(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<String>> {
+        let mut class = self.lock()?;
+
+        match class.superclass(cp)? {
+            Some(superclass) => superclass.lock()?.name(cp).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns an iterator that lazily ascends the superclass chain of current [Class],
+    /// starting from its direct superclass and ending at `java.lang.Object`.
+    ///
+    /// Each superclass is looked up only when the iterator advances, iteration stops after
+    /// the first [Err].
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Integer")?;
+    /// let direct_superclass = class.ancestors(&mut cp).next().transpose()?;
+    /// ```
+    pub fn ancestors<'p, 'local>(
+        &mut self,
+        cp: &'p mut ClassPool<'local>,
+    ) -> Ancestors<'p, 'local> {
+        Ancestors {
+            cp,
+            current: Some(self.clone()),
+        }
+    }
+
+    /// Ascends the superclass chain of current [Class] (see [`ancestors`](Self::ancestors)),
+    /// returns the first superclass which satisfies given predicate, or [None] if no
+    /// superclass does. Current [Class] itself and interfaces are not tested.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Integer")?;
+    /// let abstract_ancestor = class.find_ancestor(&mut cp, |cp, ancestor| {
+    ///     ancestor.modifiers(cp).map(Modifiers::is_abstract_bits)
+    /// })?;
+    /// ```
+    pub fn find_ancestor<'local, F>(
+        &mut self,
+        cp: &mut ClassPool<'local>,
+        mut predicate: F,
+    ) -> Result<Option<Self>>
+    where
+        F: FnMut(&mut ClassPool<'local>, &mut Self) -> Result<bool>,
+    {
+        let mut superclass = self.superclass(cp)?;
+
+        while let Some(mut current) = superclass {
+            if predicate(cp, &mut current)? {
+                return Ok(Some(current));
+            }
+
+            superclass = current.superclass(cp)?;
+        }
+
+        Ok(None)
+    }
+
+    /// Lookups the immediately enclosing class of current [Class], returns [None] if current
+    /// [Class] is a top-level class.
+    ///
+    /// This function is equivalent to `java.lang.Class#getEnclosingClass`.
+    pub fn enclosing_class(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Self>> {
+        let mut class = self.lock()?;
+        class
+            .enclosing_class(cp)
+            .map(|opt_enclosing_class| opt_enclosing_class.map(Self::new))
+    }
+
+    /// Lookups all enclosing classes of current [Class], ordered from the innermost
+    /// enclosing class outward, returns an empty [Vec] if current [Class] is a top-level
+    /// class.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.ProcessBuilder$Redirect$Type")?;
+    /// let enclosing_chain = class.enclosing_chain(&mut cp)?;
+    ///
+    /// // [Class(java.lang.ProcessBuilder$Redirect), Class(java.lang.ProcessBuilder)]
+    /// ```
+    pub fn enclosing_chain(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Self>> {
+        let mut enclosing_chain = Vec::new();
+        let mut current = self.enclosing_class(cp)?;
+
+        while let Some(mut enclosing_class) = current {
+            current = enclosing_class.enclosing_class(cp)?;
+            enclosing_chain.push(enclosing_class);
+        }
+
+        Ok(enclosing_chain)
+    }
+
+    /// Fetches the name of the module current [Class] is a member of, returns [None] if
+    /// current [Class] is in an unnamed module, or JVM is older than Java 9 which doesn't
+    /// support modules.
+    ///
+    /// This function is equivalent to `java.lang.Class#getModule().getName()`.
+    pub fn module_name(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<String>> {
+        if !cp.java_version()?.supports_modules() {
+            return Ok(None);
+        }
+
+        let class = self.lock()?;
+        class.module_name(cp)
+    }
+
+    /// Reads a resource associated with the class fully, returns [None] if the resource
+    /// cannot be found. The resource name is resolved relatively to the class' package
+    /// unless it starts with `/`.
+    ///
+    /// This function is equivalent to reading all bytes from
+    /// `java.lang.Class#getResourceAsStream`.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Object")?;
+    /// let bytes = class.resource_bytes(&mut cp, "Object.class")?;
+    ///
+    /// assert!(bytes.is_some());
+    /// ```
+    pub fn resource_bytes(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        name: &str,
+    ) -> Result<Option<Vec<u8>>> {
+        let class = self.lock()?;
+        class.resource_bytes(cp, name)
+    }
+
+    /// Reads the source file name recorded in the `SourceFile` attribute of current class'
+    /// class file, e.g. `Map.java` for `java.util.Map$Entry`.
+    ///
+    /// Since `java.lang.Class` doesn't expose the attribute, the class file is read by
+    /// [`resource_bytes`](Self::resource_bytes) and parsed. [None] is returned if the class
+    /// file is unavailable (e.g. primitive types, array types, and hidden classes generated
+    /// at runtime), malformed, or compiled without the attribute (`javac -g:none`).
+    pub fn source_file(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<String>> {
+        if matches!(self.kind(cp)?, ClassKind::Primitive | ClassKind::Array) {
+            return Ok(None);
+        }
+
+        let class_file_name = self.with_name(cp, |name| {
+            format!("{}.class", name.rsplit('.').next().unwrap_or(name))
+        })?;
+
+        Ok(self
+            .resource_bytes(cp, &class_file_name)?
+            .and_then(|bytes| classfile::source_file(&bytes)))
+    }
+
     /// Fetches class name.
     ///
     /// This function is equivalent to `java.lang.Class#getName`.
@@ -61,6 +261,33 @@ impl Class {
         class.name(cp)
     }
 
+    /// Calls given closure with the cached class name (see [`name`](Self::name)), which is
+    /// borrowed rather than cloned, so hot read paths don't allocate once the name is cached.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Integer")?;
+    /// let is_java_lang = class.with_name(&mut cp, |name| name.starts_with("java.lang."))?;
+    /// ```
+    pub fn with_name<R, F>(&mut self, cp: &mut ClassPool<'_>, f: F) -> Result<R>
+    where
+        F: FnOnce(&str) -> R,
+    {
+        let class = self.lock()?;
+        class.cached_name(cp).map(f)
+    }
+
+    /// Fetches class name in JNI form, e.g. `java/util/Map$Entry` for `java.util.Map$Entry`
+    /// and `[Ljava/lang/String;` for `java.lang.String[]`, which is the same form used by
+    /// [ClassPath::JNI]. See [`name`](Self::name) for the dotted form.
+    pub fn jni_name(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        // `java.lang.Class#getName` already uses descriptor form for array classes, only
+        // package separators differ
+        self.name(cp).map(|class_name| class_name.replace('.', "/"))
+    }
+
     /// Returns class' access flags. See [Modifiers] for all possible modifiers that would
     /// OR-ing together.
     ///
@@ -78,6 +305,24 @@ impl Class {
         class.modifiers(cp)
     }
 
+    /// Returns class' modifiers as space separated keywords, in the canonical order defined
+    /// by the JDK.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Modifier#toString(getModifiers())`.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.String")?;
+    ///
+    /// assert_eq!(class.modifiers_string(&mut cp)?, "public final");
+    /// ```
+    pub fn modifiers_string(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        let mut class = self.lock()?;
+        class.modifiers_string(cp)
+    }
+
     /// Returns array of interface [Class] that represents the interfaces implemented by
     /// current [Class].
     ///
@@ -109,251 +354,2129 @@ impl Class {
         let mut class = self.lock()?;
         class
             .interfaces(cp)
-            .map(|interfaces| interfaces.iter().map(Arc::clone).map(Class::new).collect())
+            .map(|interfaces| interfaces.into_iter().map(Class::new).collect())
     }
 
-    /// Determines if the class or interface represented by this [Class] is either the same as,
-    /// or is a superclass or superinterface of, the class or interface represented by the specified
-    /// [Class] parameter.
-    ///
-    /// If this [Class] represents primitive types, then returns true if the specified [Class] is exactly
-    /// same, otherwise false.
-    ///
-    /// # Example
-    ///
-    /// ```rs
-    /// let mut env = jni_env()?;
-    /// let mut class1 = env.lookup_class("java.lang.Integer")?;
-    /// let mut class2 = env.lookup_class("java.lang.Number")?;
-    /// let is_assignable = class2.is_assignable_from(&mut class1)?;
+    /// Returns the position of given interface in current [Class]'s declared interfaces, or
+    /// [None] if given interface is not directly implemented (or extended) by current [Class].
     ///
-    /// assert_eq!(is_assignable, true);
-    /// ```
-    pub fn is_assignable_from(&mut self, cp: &mut ClassPool<'_>, other: &Self) -> Result<bool> {
-        let mut class = self.lock()?;
-        let other = other.lock()?;
-        class.is_assignable_from(cp, &other)
-    }
+    /// The position follows `java.lang.Class#getInterfaces`, which is the declaration order
+    /// in class file's interface table.
+    pub fn interface_index(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        interface: &Self,
+    ) -> Result<Option<usize>> {
+        for (index, mut declared_interface) in self.interfaces(cp)?.into_iter().enumerate() {
+            if declared_interface.is_same_class(cp, interface)? {
+                return Ok(Some(index));
+            }
+        }
 
-    /// Determines if the class is an interface.
-    pub fn is_interface(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        let mut class = self.lock()?;
-        class.is_interface(cp)
+        Ok(None)
     }
 
-    /// Determines if the class is an annotation interface.
-    pub fn is_annotation(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        let mut class = self.lock()?;
-        class.is_annotation(cp)
+    /// Summarizes interfaces directly implemented (or extended) by current [Class] in
+    /// declaration order, see [`interfaces`](Self::interfaces).
+    ///
+    /// Unlike [`interfaces`](Self::interfaces), interfaces are neither cached by given
+    /// [ClassPool] nor materialized as [Class]es, which avoids cache pollution for read-only
+    /// scans.
+    pub fn interface_summaries(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<ClassSummary>> {
+        let class = self.lock()?;
+        class.interface_summaries(cp)
     }
 
-    /// Determines if the class has synthetic modifier bit set.
-    pub fn is_synthetic(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        let mut class = self.lock()?;
-        class.is_synthetic(cp)
+    /// Returns direct supertypes of current [Class], which is its superclass if exists,
+    /// followed by its interfaces in declaration order.
+    ///
+    /// `java.lang.Object` has no direct supertype, while interfaces only have their
+    /// extended interfaces.
+    pub fn direct_supertypes(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Self>> {
+        let superclass = self.superclass(cp)?;
+        let interfaces = self.interfaces(cp)?;
+
+        Ok(superclass.into_iter().chain(interfaces).collect())
     }
-}
 
-impl Deref for Class {
-    type Target = Arc<Mutex<ClassInternal>>;
+    /// Returns all interfaces implemented by current [Class] and its superclasses, including
+    /// interfaces extended by those interfaces transitively.
+    ///
+    /// Interfaces are ordered by visiting current [Class] first and then its superclasses,
+    /// each class' interfaces are visited depth-first in declaration order. Duplicated
+    /// interfaces only appear at their first occurrence.
+    pub fn all_interfaces(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Self>> {
+        let mut visited = HashSet::new();
+        let mut all_interfaces = Vec::new();
+        let mut class = Some(self.clone());
+
+        while let Some(mut current) = class {
+            let mut pending = current.interfaces(cp)?;
+
+            pending.reverse();
+
+            while let Some(mut interface) = pending.pop() {
+                if visited.insert(Arc::as_ptr(&interface)) {
+                    let mut super_interfaces = interface.interfaces(cp)?;
+
+                    super_interfaces.reverse();
+                    pending.append(&mut super_interfaces);
+                    all_interfaces.push(interface);
+                }
+            }
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
-    }
-}
+            class = current.superclass(cp)?;
+        }
 
-impl Display for Class {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.inner.fmt(f)
+        Ok(all_interfaces)
     }
-}
-
-/// A pseudo java class that projects `java.lang.Class`.
-pub struct ClassInternal {
-    inner: GlobalRef,
-    superclass: OnceCell<Option<Weak<Mutex<Self>>>>,
-    interfaces: OnceCell<Vec<Arc<Mutex<Self>>>>,
-    class_name: OnceCell<String>,
-    modifiers: OnceCell<u16>,
-}
 
-impl ClassInternal {
-    pub(crate) const CLASS_JNI_CP: &'static str = "java/lang/Class";
-    pub(crate) const OBJECT_JNI_CP: &'static str = "java/lang/Object";
+    /// Determines if current [Class] and given [Class] have structurally equal hierarchies,
+    /// i.e. both superclass chains have the same names in the same order, and both have the
+    /// same set of interface names, see [`all_interfaces`](Self::all_interfaces). Classes
+    /// themselves are not compared.
+    pub fn hierarchy_equals(&mut self, cp: &mut ClassPool<'_>, other: &mut Self) -> Result<bool> {
+        fn superclass_names(cp: &mut ClassPool<'_>, class: &mut Class) -> Result<Vec<String>> {
+            let mut superclass_names = Vec::new();
+            let mut superclass = class.superclass(cp)?;
+
+            while let Some(mut current) = superclass {
+                superclass_names.push(current.name(cp)?);
+                superclass = current.superclass(cp)?;
+            }
 
-    /// Creates new [Class] from an [GlobalRef] that stores reference to
-    /// [JClass] as internal backend.
-    pub(crate) fn new(class_obj: GlobalRef) -> Self {
-        Self {
-            superclass: OnceCell::new(),
-            inner: class_obj,
-            class_name: OnceCell::new(),
-            modifiers: OnceCell::new(),
-            interfaces: OnceCell::new(),
+            Ok(superclass_names)
         }
-    }
 
-    fn superclass(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Arc<Mutex<Self>>>> {
-        self.superclass
-            .get_or_try_init(|| {
-                let Some(superclass) = cp.get_superclass(&self.inner)? else {
-                    return Ok(None);
-                };
-                let cached_superclass = cp.fetch_class_from_jclass(&superclass, None)?;
+        fn interface_names(cp: &mut ClassPool<'_>, class: &mut Class) -> Result<HashSet<String>> {
+            class
+                .all_interfaces(cp)?
+                .iter_mut()
+                .map(|interface| interface.name(cp))
+                .collect()
+        }
 
-                Ok(Some(Arc::downgrade(&cached_superclass)))
-            })
-            .map(Option::as_ref)
-            .map(|opt_superclass| opt_superclass.and_then(Weak::upgrade))
+        Ok(superclass_names(cp, self)? == superclass_names(cp, other)?
+            && interface_names(cp, self)? == interface_names(cp, other)?)
     }
 
-    fn name(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
-        self.class_name
-            .get_or_try_init(|| {
-                cp.push_local_frame(1)?;
-
-                let method_id =
-                    cp.get_method_id(Self::CLASS_JNI_CP, "getName", "()Ljava/lang/String;")?;
-                let class_name: JString = unsafe {
-                    cp.call_method_unchecked(&self.inner, method_id, ReturnType::Object, &[])
-                        .and_then(JValueGen::l)
-                        .map(Into::into)?
-                };
-
-                let string = unsafe {
-                    cp.get_string_unchecked(&class_name)
-                        .map(Into::<String>::into)
-                };
-
-                unsafe {
-                    cp.pop_local_frame(&JObject::null())?;
+    /// Returns all interfaces implemented by current [Class] and its superclasses like
+    /// [`all_interfaces`](Self::all_interfaces), each paired with the shortest amount of
+    /// superclass or interface hops from current [Class], e.g. direct interfaces are 1 hop
+    /// away, and interfaces of superclass are 2 hops away.
+    ///
+    /// Interfaces are ordered by their depths, interfaces with the same depth are ordered by
+    /// visiting supertypes breadth-first in declaration order.
+    pub fn interface_depths(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<(Self, usize)>> {
+        let mut visited = HashSet::from([Arc::as_ptr(self)]);
+        let mut pending = VecDeque::from([(self.clone(), 0)]);
+        let mut interface_depths = Vec::new();
+
+        while let Some((mut class, depth)) = pending.pop_front() {
+            for mut supertype in class.direct_supertypes(cp)? {
+                if !visited.insert(Arc::as_ptr(&supertype)) {
+                    continue;
                 }
 
-                string
-            })
-            .cloned()
-            .map_err(Into::into)
-    }
+                if supertype.is_interface(cp)? {
+                    interface_depths.push((supertype.clone(), depth + 1));
+                }
 
-    fn modifiers(&mut self, cp: &mut ClassPool<'_>) -> Result<u16> {
-        self.modifiers
-            .get_or_try_init(|| {
-                let method_id = cp.get_method_id(Self::CLASS_JNI_CP, "getModifiers", "()I")?;
+                pending.push_back((supertype, depth + 1));
+            }
+        }
 
-                unsafe {
-                    cp.call_method_unchecked(
-                        &self.inner,
-                        method_id,
-                        ReturnType::Primitive(Primitive::Int),
-                        &[],
-                    )
-                    .and_then(JValueOwned::i)
-                    .map(|modifiers| modifiers as u16)
-                }
-            })
-            .copied()
-            .map_err(Into::into)
+        Ok(interface_depths)
     }
 
-    fn interfaces(&mut self, cp: &mut ClassPool<'_>) -> Result<&Vec<Arc<Mutex<Self>>>> {
-        self.interfaces.get_or_try_init(|| {
-            cp.push_local_frame(1)?;
-            let method_id =
-                cp.get_method_id(Self::CLASS_JNI_CP, "getInterfaces", "()[Ljava/lang/Class;")?;
-            let interface_arr: JObjectArray = unsafe {
-                cp.call_method_unchecked(&self.inner, method_id, ReturnType::Array, &[])
-                    .and_then(JValueGen::l)?
-                    .into()
-            };
-            let interfaces_len = cp.get_array_length(&interface_arr)?;
-            let mut interfaces = Vec::with_capacity(interfaces_len as usize);
-
-            for i in 0..interfaces_len {
-                let interface_class = cp.get_object_array_element(&interface_arr, i)?.into();
-                let interface_class = cp.fetch_class_from_jclass(&interface_class, None)?;
+    /// Determines if a class with given name (in `java.lang.Class#getName`'s form) is one of
+    /// current [Class]'s superclasses (see [`ancestors`](Self::ancestors)) or interfaces
+    /// (see [`all_interfaces`](Self::all_interfaces)), current [Class] itself is excluded.
+    ///
+    /// Superclasses are checked first, and the walk stops at the first match.
+    pub fn hierarchy_contains(&mut self, cp: &mut ClassPool<'_>, name: &str) -> Result<bool> {
+        let mut superclass = self.superclass(cp)?;
 
-                interfaces.push(interface_class);
+        while let Some(mut current) = superclass {
+            if current.name(cp)? == name {
+                return Ok(true);
             }
 
-            unsafe {
-                cp.pop_local_frame(&JObject::null())?;
+            superclass = current.superclass(cp)?;
+        }
+
+        for mut interface in self.all_interfaces(cp)? {
+            if interface.name(cp)? == name {
+                return Ok(true);
             }
+        }
 
-            Ok(interfaces)
-        })
+        Ok(false)
     }
 
-    fn is_assignable_from(&mut self, cp: &mut ClassPool<'_>, other: &Self) -> Result<bool> {
-        // FIXME: Should we explore the both classes class hierarchy and so the
-        // whole hierarchy tree can be cached and used later for better performance?
-        let method_id = cp.get_method_id(
-            Self::CLASS_JNI_CP,
-            "isAssignableFrom",
-            "(Ljava/lang/Class;)Z",
-        )?;
+    /// Returns every `(from, to)` edge where `to` is a direct interface of `from`, starting
+    /// from current [Class] and recursively through the extended interfaces. Unlike
+    /// [`all_interfaces`](Self::all_interfaces), the diamond structure is preserved, e.g.
+    /// `D extends B, C` with `B, C extends A` yields `D -> B`, `D -> C`, `B -> A` and `C -> A`.
+    ///
+    /// Edges are ordered depth-first in declaration order, each interface's edges only
+    /// appear once. Interfaces of superclasses are not included.
+    pub fn interfaces_recursive_edges(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+    ) -> Result<Vec<(Self, Self)>> {
+        let mut visited = HashSet::new();
+        let mut edges = Vec::new();
+        let mut pending = vec![self.clone()];
+
+        while let Some(mut current) = pending.pop() {
+            if !visited.insert(Arc::as_ptr(&current)) {
+                continue;
+            }
 
-        unsafe {
-            cp.call_method_unchecked(
-                &self.inner,
-                method_id,
-                ReturnType::Primitive(Primitive::Boolean),
-                &[Into::<JValue>::into(&other.inner).as_jni()],
-            )
-            .and_then(JValueOwned::z)
-            .map_err(Into::into)
+            let interfaces = current.interfaces(cp)?;
+
+            for interface in interfaces.iter().rev() {
+                pending.push(interface.clone());
+            }
+
+            edges.extend(
+                interfaces
+                    .into_iter()
+                    .map(|interface| (current.clone(), interface)),
+            );
         }
-    }
 
-    fn is_interface(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        self.modifiers(cp).map(Modifiers::is_interface_bits)
+        Ok(edges)
     }
 
-    fn is_annotation(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        self.modifiers(cp).map(Modifiers::is_annotation_bits)
+    /// Returns all methods declared by current [Class], including public, protected, default
+    /// (package) access, and private methods, but excluding inherited methods.
+    ///
+    /// This function is equivalent to `java.lang.Class#getDeclaredMethods`.
+    pub fn declared_methods(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Method>> {
+        let class = self.lock()?;
+        class.methods(cp, "getDeclaredMethods")
     }
 
-    fn is_synthetic(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
-        self.modifiers(cp).map(Modifiers::is_synthetic_bits)
+    /// Returns all fields declared by current [Class], including public, protected, default
+    /// (package) access, and private fields, but excluding inherited fields.
+    ///
+    /// This function is equivalent to `java.lang.Class#getDeclaredFields`.
+    pub fn declared_fields(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Field>> {
+        let class = self.lock()?;
+        class.declared_fields(cp)
     }
-}
 
-impl Display for ClassInternal {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Class({})",
-            self.class_name.get().unwrap_or(&"...".to_owned())
-        )
+    /// Resolves a field by its name, searches fields declared by current [Class] first, then
+    /// ascends its superclasses, returns the declaring [Class] with the [Field]. Since the
+    /// most-derived match is returned, a field shadowed by a subclass' field is never
+    /// returned. Fields declared by interfaces are not searched.
+    pub fn resolve_field(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        name: &str,
+    ) -> Result<Option<(Self, Field)>> {
+        let mut class = Some(self.clone());
+
+        while let Some(mut current) = class {
+            for mut field in current.declared_fields(cp)? {
+                if field.name(cp)? == name {
+                    return Ok(Some((current, field)));
+                }
+            }
+
+            class = current.superclass(cp)?;
+        }
+
+        Ok(None)
     }
-}
 
-#[cfg(all(test, feature = "invocation"))]
+    /// Returns methods declared by current [Class] which satisfy given predicate, see
+    /// [`declared_methods`](Self::declared_methods).
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Integer")?;
+    /// let static_methods = class.find_methods(&mut cp, |cp, method| {
+    ///     method.modifiers(cp).map(Modifiers::is_static_bits)
+    /// })?;
+    /// ```
+    pub fn find_methods<'local, F>(
+        &mut self,
+        cp: &mut ClassPool<'local>,
+        mut predicate: F,
+    ) -> Result<Vec<Method>>
+    where
+        F: FnMut(&mut ClassPool<'local>, &mut Method) -> Result<bool>,
+    {
+        let mut methods = Vec::new();
+
+        for mut method in self.declared_methods(cp)? {
+            if predicate(cp, &mut method)? {
+                methods.push(method);
+            }
+        }
+
+        Ok(methods)
+    }
+
+    /// Resolves the most specific method applicable to arguments of given types, searches
+    /// methods declared by current [Class] and its superclasses, then public methods
+    /// inherited from superinterfaces. A method overridden by a subclass is only considered
+    /// once, as the most-derived one.
+    ///
+    /// A method is applicable if it has given name and each of its parameter types is
+    /// assignable from the corresponding argument type, see [`is_assignable_from`](Self::is_assignable_from).
+    /// Among applicable methods, the one whose parameter types are all assignable to every
+    /// other applicable method's parameter types is the most specific. Returns [None] if no
+    /// method is applicable, or multiple applicable methods are equally specific.
+    ///
+    /// Unlike Java's method resolution, primitive widening, boxing, unboxing and variable
+    /// arity invocation are not considered, e.g. an `int` argument doesn't apply to a `long`
+    /// or `java.lang.Integer` parameter.
+    pub fn most_specific_method(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        name: &str,
+        arg_types: &[Self],
+    ) -> Result<Option<Method>> {
+        let mut candidates = Vec::new();
+        let mut class = Some(self.clone());
+
+        while let Some(mut current) = class {
+            candidates.append(&mut current.declared_methods(cp)?);
+            class = current.superclass(cp)?;
+        }
+
+        candidates.append(&mut self.public_methods(cp)?);
+
+        let mut signatures = HashSet::new();
+        let mut applicable_methods = Vec::<(Method, Vec<Self>)>::new();
+
+        'candidates: for mut method in candidates {
+            if method.name(cp)? != name {
+                continue;
+            }
+
+            let mut parameter_types = method.parameter_types(cp)?;
+
+            if parameter_types.len() != arg_types.len() {
+                continue;
+            }
+
+            let signature = parameter_types
+                .iter_mut()
+                .map(|parameter_type| parameter_type.name(cp))
+                .collect::<Result<Vec<_>>>()?;
+
+            if !signatures.insert(signature) {
+                continue;
+            }
+
+            for (parameter_type, arg_type) in parameter_types.iter_mut().zip(arg_types) {
+                if !parameter_type.is_assignable_from(cp, arg_type)? {
+                    continue 'candidates;
+                }
+            }
+
+            applicable_methods.push((method, parameter_types));
+        }
+
+        let mut most_specific_methods = Vec::new();
+
+        for (i, (method, parameter_types)) in applicable_methods.iter().enumerate() {
+            let mut is_most_specific = true;
+
+            for (j, (_, other_parameter_types)) in applicable_methods.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                for (parameter_type, other_parameter_type) in
+                    parameter_types.iter().zip(other_parameter_types)
+                {
+                    if !other_parameter_type
+                        .clone()
+                        .is_assignable_from(cp, parameter_type)?
+                    {
+                        is_most_specific = false;
+                        break;
+                    }
+                }
+            }
+
+            if is_most_specific {
+                most_specific_methods.push(method.clone());
+            }
+        }
+
+        if most_specific_methods.len() == 1 {
+            Ok(most_specific_methods.pop())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the single abstract method of a functional interface, e.g. `apply` of
+    /// `java.util.function.Function`. Abstract methods matching a public method of
+    /// `java.lang.Object` (e.g. `equals` of `java.util.Comparator`), default methods and
+    /// static methods are not counted. Returns [None] if current [Class] is not an interface,
+    /// is an annotation interface, or doesn't have exactly one abstract method.
+    ///
+    /// Abstract methods are compared by their erased signatures, so an interface which
+    /// overrides a generic method with a more specific parameter type is not considered as
+    /// a functional interface.
+    pub fn functional_method(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Method>> {
+        if !self.is_interface(cp)? || self.is_annotation(cp)? {
+            return Ok(None);
+        }
+
+        fn signature(cp: &mut ClassPool<'_>, method: &mut Method) -> Result<Vec<String>> {
+            let mut signature = vec![method.name(cp)?];
+
+            for mut parameter_type in method.parameter_types(cp)? {
+                signature.push(parameter_type.name(cp)?);
+            }
+
+            Ok(signature)
+        }
+
+        let mut signatures = HashSet::new();
+
+        for mut method in cp.lookup_class("java.lang.Object")?.public_methods(cp)? {
+            signatures.insert(signature(cp, &mut method)?);
+        }
+
+        let mut functional_method = None;
+
+        for mut method in self.public_methods(cp)? {
+            if !Modifiers::is_abstract_bits(method.modifiers(cp)?)
+                || !signatures.insert(signature(cp, &mut method)?)
+            {
+                continue;
+            }
+
+            if functional_method.is_some() {
+                return Ok(None);
+            }
+
+            functional_method = Some(method);
+        }
+
+        Ok(functional_method)
+    }
+
+    /// Returns all public methods of current [Class], including those inherited from
+    /// superclasses and superinterfaces (e.g. interface default methods). Unlike
+    /// [`declared_methods`](Self::declared_methods), non-public methods are excluded.
+    ///
+    /// This function is equivalent to `java.lang.Class#getMethods`.
+    pub fn public_methods(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Method>> {
+        let class = self.lock()?;
+        class.methods(cp, "getMethods")
+    }
+
+    /// Returns annotation types of annotations directly present on current [Class], which
+    /// excludes annotations inherited from superclasses.
+    ///
+    /// This function is equivalent to calling `annotationType` on each annotation returned
+    /// by `java.lang.Class#getDeclaredAnnotations`.
+    pub fn declared_annotation_types(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Self>> {
+        let class = self.lock()?;
+        class
+            .declared_annotation_types(cp)
+            .map(|annotation_types| annotation_types.into_iter().map(Self::new).collect())
+    }
+
+    /// Determines if the class or interface represented by this [Class] is either the same as,
+    /// or is a superclass or superinterface of, the class or interface represented by the specified
+    /// [Class] parameter.
+    ///
+    /// If this [Class] represents primitive types, then returns true if the specified [Class] is exactly
+    /// same, otherwise false.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut env = jni_env()?;
+    /// let mut class1 = env.lookup_class("java.lang.Integer")?;
+    /// let mut class2 = env.lookup_class("java.lang.Number")?;
+    /// let is_assignable = class2.is_assignable_from(&mut class1)?;
+    ///
+    /// assert_eq!(is_assignable, true);
+    /// ```
+    pub fn is_assignable_from(&mut self, cp: &mut ClassPool<'_>, other: &Self) -> Result<bool> {
+        // Both handles share the same lock when they refer to the same cached class
+        if Arc::ptr_eq(self, other) {
+            return Ok(true);
+        }
+
+        let mut class = self.lock()?;
+        let mut other = other.lock()?;
+        class.is_assignable_from(cp, &mut other)
+    }
+
+    /// Determines if current [Class] and given [Class] refer to the exact same
+    /// `java.lang.Class` object, unlike comparing names, classes with same name but loaded
+    /// by different class loaders are distinguished.
+    pub fn is_same_class(&mut self, cp: &mut ClassPool<'_>, other: &Self) -> Result<bool> {
+        // Both handles share the same lock when they refer to the same cached class
+        if Arc::ptr_eq(self, other) {
+            return Ok(true);
+        }
+
+        let class = self.lock()?;
+        let other = other.lock()?;
+        cp.is_same_object(&class.inner, &other.inner)
+            .map_err(Into::into)
+    }
+
+    /// Determines if given [JClass] refers to the same `java.lang.Class` object as current
+    /// [Class].
+    pub(crate) fn is_same_jclass(
+        &self,
+        cp: &mut ClassPool<'_>,
+        jclass: &JClass<'_>,
+    ) -> Result<bool> {
+        let class = self.lock()?;
+        cp.is_same_object(&class.inner, jclass).map_err(Into::into)
+    }
+
+    /// Determines if current [Class] is defined by given class loader, a `null` loader stands
+    /// for the bootstrap class loader.
+    pub(crate) fn is_defined_by(
+        &self,
+        cp: &mut ClassPool<'_>,
+        loader: &JObject<'_>,
+    ) -> Result<bool> {
+        let class = self.lock()?;
+
+        cp.with_local_frame(1, |env| {
+            let defining_loader = env
+                .call_method(
+                    &class.inner,
+                    "getClassLoader",
+                    "()Ljava/lang/ClassLoader;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?;
+
+            env.is_same_object(&defining_loader, loader)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Determines if given object is an instance of current [Class], the cached
+    /// `java.lang.Class` object is used directly so the class is never resolved again by
+    /// name. Unlike JNI's `IsInstanceOf`, `null` is not an instance of any class.
+    ///
+    /// This function is equivalent to `java.lang.Class#isInstance`.
+    pub fn is_instance_of_cached(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        obj: &JObject<'_>,
+    ) -> Result<bool> {
+        if obj.is_null() {
+            return Ok(false);
+        }
+
+        let class = self.lock()?;
+        let class_obj: &JClass = class.inner.as_obj().into();
+
+        cp.is_instance_of(obj, class_obj).map_err(Into::into)
+    }
+
+    /// Determines if current [Class] is a superinterface of given [Class], i.e. both are
+    /// interfaces and given interface extends current interface directly or transitively.
+    /// Unlike [`is_assignable_from`](Self::is_assignable_from), classes implementing current
+    /// interface are excluded, and an interface is not a superinterface of itself.
+    pub fn is_superinterface_of(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        other: &mut Self,
+    ) -> Result<bool> {
+        if !self.is_interface(cp)? || !other.is_interface(cp)? || self.is_same_class(cp, other)? {
+            return Ok(false);
+        }
+
+        self.is_assignable_from(cp, other)
+    }
+
+    /// Returns the shortest amount of superclass or interface hops from `sub` up to current
+    /// [Class], or [None] if current [Class] is not assignable from `sub`. A class is 0 hop
+    /// away from itself.
+    ///
+    /// Interfaces, which have no superclass, are considered 1 hop away from `java.lang.Object`.
+    /// For reference array types, the distance is measured between their component types,
+    /// e.g. `Object[]` is 2 hops away from `Integer[]`.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut object_class = cp.lookup_class("java.lang.Object")?;
+    /// let mut integer_class = cp.lookup_class("java.lang.Integer")?;
+    ///
+    /// // Integer -> Number -> Object
+    /// assert_eq!(object_class.assignability_distance(&mut cp, &mut integer_class)?, Some(2));
+    /// ```
+    pub fn assignability_distance(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        sub: &mut Self,
+    ) -> Result<Option<usize>> {
+        if !self.is_assignable_from(cp, sub)? {
+            return Ok(None);
+        }
+
+        let is_object = self.name(cp)? == "java.lang.Object";
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::from([(sub.clone(), 0)]);
+
+        while let Some((mut class, distance)) = pending.pop_front() {
+            if self.is_same_class(cp, &class)? {
+                return Ok(Some(distance));
+            }
+
+            if !visited.insert(Arc::as_ptr(&class)) {
+                continue;
+            }
+
+            let superclass = class.superclass(cp)?;
+
+            // Interfaces have no superclass but are still assignable to java.lang.Object
+            if is_object && superclass.is_none() && class.is_interface(cp)? {
+                return Ok(Some(distance + 1));
+            }
+
+            for supertype in superclass.into_iter().chain(class.interfaces(cp)?) {
+                pending.push_back((supertype, distance + 1));
+            }
+        }
+
+        // Array covariance, e.g. Object[] is assignable from Integer[]
+        let component_class_paths = (
+            Self::component_class_path(&self.name(cp)?),
+            Self::component_class_path(&sub.name(cp)?),
+        );
+
+        if let (Some(component_cp), Some(sub_component_cp)) = component_class_paths {
+            let mut component = cp.lookup_class(ClassPath::JNI(component_cp))?;
+            let mut sub_component = cp.lookup_class(ClassPath::JNI(sub_component_cp))?;
+
+            return component.assignability_distance(cp, &mut sub_component);
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the JNI class path of component type if given class name represents a
+    /// reference array type.
+    fn component_class_path(class_name: &str) -> Option<String> {
+        let component_name = class_name.strip_prefix('[')?;
+
+        if component_name.starts_with('[') {
+            Some(component_name.replace('.', "/"))
+        } else {
+            component_name
+                .strip_prefix('L')
+                .and_then(|component_name| component_name.strip_suffix(';'))
+                .map(|component_name| component_name.replace('.', "/"))
+        }
+    }
+
+    /// Determines if the class is annotated by `java.lang.Deprecated`.
+    ///
+    /// This function is equivalent to `java.lang.Class#isAnnotationPresent(Deprecated.class)`.
+    pub fn is_deprecated(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let class = self.lock()?;
+        class.is_deprecated(cp)
+    }
+
+    /// Determines if the class declares any type parameter, e.g. `java.util.List<E>`.
+    pub fn is_generic(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.type_parameter_names(cp)
+            .map(|type_parameter_names| !type_parameter_names.is_empty())
+    }
+
+    /// Returns names of type variables declared by the class in declaration order, e.g.
+    /// `["K", "V"]` for `java.util.Map`.
+    ///
+    /// This function is equivalent to calling `getName` on each type variable returned by
+    /// `java.lang.Class#getTypeParameters`.
+    pub fn type_parameter_names(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<String>> {
+        let class = self.lock()?;
+        class.type_parameter_names(cp)
+    }
+
+    /// Determines if the class is an interface.
+    pub fn is_interface(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let mut class = self.lock()?;
+        class.is_interface(cp)
+    }
+
+    /// Determines if the class is an annotation interface.
+    pub fn is_annotation(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let mut class = self.lock()?;
+        class.is_annotation(cp)
+    }
+
+    /// Determines if the class is an inner class, which is a member class without `static`
+    /// modifier, so its instances capture an enclosing instance, e.g. its constructors take
+    /// the enclosing instance as the first parameter.
+    ///
+    /// Static nested classes, local classes and anonymous classes are not inner classes
+    /// by this definition.
+    pub fn is_inner_class(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let mut class = self.lock()?;
+        class.is_inner_class(cp)
+    }
+
+    /// Determines if current [Class] is accessible from given package, i.e. the class is
+    /// public, or it's in the same package (package-private access). Package names are in
+    /// Java form, e.g. `java.util`, with an empty string being the default package.
+    ///
+    /// A nested class is treated as public only if it and all its enclosing classes are
+    /// public, private and protected nested classes are not distinguished from
+    /// package-private ones.
+    pub fn is_accessible_from(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        from_package: &str,
+    ) -> Result<bool> {
+        let package = self.with_name(cp, |name| ClassPath::JNI(name.to_string()).package())?;
+
+        if package == from_package {
+            return Ok(true);
+        }
+
+        if !Modifiers::is_public_bits(self.modifiers(cp)?) {
+            return Ok(false);
+        }
+
+        for mut enclosing_class in self.enclosing_chain(cp)? {
+            if !Modifiers::is_public_bits(enclosing_class.modifiers(cp)?) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Finds the synthetic field of an inner class which references its enclosing instance,
+    /// i.e. the `this$0` field emitted by `javac`, with the number suffix counting nesting
+    /// depth. Returns [None] if current [Class] is not an inner class, see
+    /// [is_inner_class](Self::is_inner_class).
+    ///
+    /// Note that since JDK 18, `javac` omits this field if the inner class never refers to
+    /// its enclosing instance.
+    pub fn outer_instance_field(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Field>> {
+        if !self.is_inner_class(cp)? {
+            return Ok(None);
+        }
+
+        for mut field in self.declared_fields(cp)? {
+            if Modifiers::is_synthetic_bits(field.modifiers(cp)?)
+                && field.name(cp)?.starts_with("this$")
+            {
+                return Ok(Some(field));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Determines if the class has synthetic modifier bit set.
+    pub fn is_synthetic(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let mut class = self.lock()?;
+        class.is_synthetic(cp)
+    }
+
+    /// Reads the value of a static field declared in or inherited by current [Class], the
+    /// field is resolved by its name and the [Class] of its type.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let mut cp = ClassPool::from_permanent_env()?;
+    /// let mut class = cp.lookup_class("java.lang.Integer")?;
+    /// let int_class = cp.lookup_class("int")?;
+    /// let max_value = class.static_field(&mut cp, "MAX_VALUE", &int_class)?.i()?;
+    ///
+    /// assert_eq!(max_value, i32::MAX);
+    /// ```
+    pub fn static_field<'local>(
+        &mut self,
+        cp: &mut ClassPool<'local>,
+        name: &str,
+        ty: &Self,
+    ) -> Result<JValueOwned<'local>> {
+        let descriptor = ty.descriptor(cp)?;
+        let class = self.lock()?;
+        class.static_field(cp, name, &descriptor)
+    }
+
+    /// Casts given object to current [Class], returns the same object as a [GlobalRef] if
+    /// the object is null or assignable to current [Class].
+    ///
+    /// This function is equivalent to `java.lang.Class#cast`.
+    ///
+    /// # Errors
+    ///
+    /// If the object is not assignable to current [Class], the thrown `ClassCastException`
+    /// is cleared and returned as [HierError::JavaException](crate::errors::HierError::JavaException).
+    pub fn cast(&mut self, cp: &mut ClassPool<'_>, obj: &JObject<'_>) -> Result<GlobalRef> {
+        let class = self.lock()?;
+        class.cast(cp, obj)
+    }
+
+    /// Returns the field descriptor of current [Class], e.g. `I` for `int`,
+    /// `Ljava/lang/String;` for `java.lang.String` and `[I` for `int[]`.
+    pub fn descriptor(&self, cp: &mut ClassPool<'_>) -> Result<String> {
+        let mut class = self.lock()?;
+        class.descriptor(cp)
+    }
+
+    /// Determines if the class is `java.lang.Throwable` or its subclass.
+    pub fn is_throwable(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.is_subtype_of(cp, "java.lang.Throwable")
+    }
+
+    /// Determines if the class is one of primitive wrapper types, e.g. `java.lang.Integer`,
+    /// including `java.lang.Void`. Only class name is compared, no class is loaded.
+    pub fn is_wrapper_type(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.with_name(cp, |name| {
+            let class_path = name.replace('.', "/");
+
+            DESC_TO_WRAPPER_CLASS_CP
+                .values()
+                .any(|wrapper_class_cp| *wrapper_class_cp == class_path)
+        })
+    }
+
+    /// Converts current [Class] into a [ThrowableClass] if it's `java.lang.Throwable` or its
+    /// subclass, otherwise returns [None]. See [`is_throwable`](Self::is_throwable).
+    pub fn as_throwable(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<ThrowableClass>> {
+        if self.is_throwable(cp)? {
+            Ok(Some(ThrowableClass(self.clone())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Determines if the class is `java.lang.Exception` or its subclass.
+    pub fn is_exception(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.is_subtype_of(cp, "java.lang.Exception")
+    }
+
+    /// Determines if the class is `java.lang.Error` or its subclass.
+    pub fn is_error(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.is_subtype_of(cp, "java.lang.Error")
+    }
+
+    /// Determines if the class is assignable to the class with given class path, which is
+    /// looked up from (and cached by) given [ClassPool].
+    fn is_subtype_of(&mut self, cp: &mut ClassPool<'_>, class_path: &str) -> Result<bool> {
+        cp.lookup_class(class_path)?.is_assignable_from(cp, self)
+    }
+
+    /// Determines the kind of type this [Class] represents.
+    ///
+    /// When multiple kinds apply, the first matching kind in the following order wins:
+    /// [ClassKind::Array], [ClassKind::Primitive], [ClassKind::Annotation] (which is also an
+    /// interface), [ClassKind::Interface], [ClassKind::Enum], [ClassKind::Record], and
+    /// finally [ClassKind::Class].
+    ///
+    /// Like `java.lang.Class#isEnum`, the class body of an enum constant is not an enum.
+    pub fn kind(&mut self, cp: &mut ClassPool<'_>) -> Result<ClassKind> {
+        let mut class = self.lock()?;
+        class.kind(cp)
+    }
+
+    /// Collects all superclasses and transitively implemented or extended interfaces of
+    /// current [Class], excluding itself.
+    ///
+    /// The walk relies on each class' cached superclass and interfaces, so once a hierarchy
+    /// is visited, later walks won't hit JNI.
+    pub(crate) fn all_supertypes(&self, cp: &mut ClassPool<'_>) -> Result<Vec<Self>> {
+        let mut visited = HashSet::new();
+        let mut ancestors = Vec::new();
+        let mut pending = vec![self.clone()];
+
+        while let Some(class) = pending.pop() {
+            let (superclass, interfaces) = {
+                let mut class = class.lock()?;
+                let superclass = class.superclass(cp)?;
+                let interfaces = class.interfaces(cp)?;
+
+                (superclass, interfaces)
+            };
+
+            for ancestor in superclass.into_iter().chain(interfaces).map(Self::new) {
+                if visited.insert(Arc::as_ptr(&ancestor)) {
+                    pending.push(ancestor.clone());
+                    ancestors.push(ancestor);
+                }
+            }
+        }
+
+        Ok(ancestors)
+    }
+}
+
+impl Deref for Class {
+    type Target = Arc<Mutex<ClassInternal>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let class = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        class.fmt(f)
+    }
+}
+
+/// An iterator over superclasses of a [Class], see [`Class::ancestors`].
+pub struct Ancestors<'p, 'local> {
+    cp: &'p mut ClassPool<'local>,
+    current: Option<Class>,
+}
+
+impl Iterator for Ancestors<'_, '_> {
+    type Item = Result<Class>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = self.current.take()?;
+
+        match current.superclass(self.cp) {
+            Ok(superclass) => {
+                self.current = superclass.clone();
+                superclass.map(Ok)
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// A [Class] known to be `java.lang.Throwable` or its subclass, see [`Class::as_throwable`].
+#[derive(Clone)]
+pub struct ThrowableClass(Class);
+
+impl ThrowableClass {
+    /// Constructs a new throwable with given message by its `(String)` constructor.
+    ///
+    /// # Errors
+    ///
+    /// If the class has no such constructor or can't be instantiated, e.g. it's abstract, the
+    /// thrown exception is cleared and returned as
+    /// [HierError::JavaException](crate::errors::HierError::JavaException).
+    pub fn new_instance_with_message(
+        &self,
+        cp: &mut ClassPool<'_>,
+        message: &str,
+    ) -> Result<GlobalRef> {
+        let class = self.0.lock()?;
+        let message = cp.new_string(message)?;
+        let message = cp.auto_local(message);
+        let class_obj: &JClass = class.inner.as_obj().into();
+        let result = cp.new_object(class_obj, "(Ljava/lang/String;)V", &[(&*message).into()]);
+        let throwable = cp.catch_java_exception(result)?;
+        let throwable = cp.auto_local(throwable);
+
+        cp.new_global_ref(&*throwable).map_err(Into::into)
+    }
+
+    /// Returns the underlying [Class].
+    pub fn into_inner(self) -> Class {
+        self.0
+    }
+}
+
+impl Deref for ThrowableClass {
+    type Target = Class;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A pseudo java class that projects `java.lang.Class`.
+pub struct ClassInternal {
+    inner: GlobalRef,
+    superclass: OnceCell<Option<Weak<Mutex<Self>>>>,
+    enclosing_class: OnceCell<Option<Weak<Mutex<Self>>>>,
+    interfaces: OnceCell<Vec<Weak<Mutex<Self>>>>,
+    class_name: OnceCell<String>,
+    modifiers: OnceCell<u16>,
+    class_path: Arc<str>,
+    identity_hash: OnceCell<i32>,
+    /// Memoized results of `is_assignable_from`, keyed by the other class'
+    /// `System#identityHashCode`. Each entry keeps a [WeakRef] of the other class to tell
+    /// classes with colliding hashes apart, without preventing the class from unloading.
+    assignable_from: HashMap<i32, Vec<(WeakRef, bool)>>,
+}
+
+impl ClassInternal {
+    pub(crate) const CLASS_JNI_CP: &'static str = "java/lang/Class";
+    pub(crate) const OBJECT_JNI_CP: &'static str = "java/lang/Object";
+    pub(crate) const MODIFIER_JNI_CP: &'static str = "java/lang/reflect/Modifier";
+    pub(crate) const SYSTEM_JNI_CP: &'static str = "java/lang/System";
+
+    /// Creates new [Class] from an [GlobalRef] that stores reference to
+    /// [JClass] as internal backend, and the class path it's cached with.
+    pub(crate) fn new(class_obj: GlobalRef, class_path: Arc<str>) -> Self {
+        Self {
+            class_path,
+            superclass: OnceCell::new(),
+            enclosing_class: OnceCell::new(),
+            inner: class_obj,
+            class_name: OnceCell::new(),
+            modifiers: OnceCell::new(),
+            interfaces: OnceCell::new(),
+            identity_hash: OnceCell::new(),
+            assignable_from: HashMap::new(),
+        }
+    }
+
+    fn superclass(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Arc<Mutex<Self>>>> {
+        if let Some(superclass) = self.superclass.get() {
+            match superclass.as_ref().map(Weak::upgrade) {
+                None => return Ok(None),
+                Some(Some(superclass)) => return Ok(Some(superclass)),
+                // Superclass is freed from class pool since it was cached, fetches again
+                Some(None) => {}
+            }
+        }
+
+        let superclass = match cp.get_superclass(&self.inner)? {
+            Some(superclass) => {
+                let superclass = cp.auto_local(superclass);
+
+                Some(cp.fetch_class_from_jclass(&superclass, None)?)
+            }
+            None => None,
+        };
+
+        self.superclass = OnceCell::with_value(superclass.as_ref().map(Arc::downgrade));
+
+        Ok(superclass)
+    }
+
+    fn enclosing_class(&mut self, cp: &mut ClassPool<'_>) -> Result<Option<Arc<Mutex<Self>>>> {
+        if let Some(enclosing_class) = self.enclosing_class.get() {
+            match enclosing_class.as_ref().map(Weak::upgrade) {
+                None => return Ok(None),
+                Some(Some(enclosing_class)) => return Ok(Some(enclosing_class)),
+                // Enclosing class is freed from class pool since it was cached, fetches again
+                Some(None) => {}
+            }
+        }
+
+        let method_id = cp.get_method_id(
+            Self::CLASS_JNI_CP,
+            "getEnclosingClass",
+            "()Ljava/lang/Class;",
+        )?;
+        let enclosing_class: JClass = unsafe {
+            cp.call_method_unchecked(&self.inner, method_id, ReturnType::Object, &[])
+                .and_then(JValueGen::l)?
+                .into()
+        };
+        let enclosing_class = cp.auto_local(enclosing_class);
+        let enclosing_class = if enclosing_class.is_null() {
+            None
+        } else {
+            Some(cp.fetch_class_from_jclass(&enclosing_class, None)?)
+        };
+
+        self.enclosing_class = OnceCell::with_value(enclosing_class.as_ref().map(Arc::downgrade));
+
+        Ok(enclosing_class)
+    }
+
+    fn name(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        self.cached_name(cp).map(ToString::to_string)
+    }
+
+    fn cached_name(&self, cp: &mut ClassPool<'_>) -> Result<&str> {
+        self.class_name
+            .get_or_try_init(|| {
+                cp.push_local_frame(1)?;
+
+                let method_id =
+                    cp.get_method_id(Self::CLASS_JNI_CP, "getName", "()Ljava/lang/String;")?;
+                let class_name: JString = unsafe {
+                    cp.call_method_unchecked(&self.inner, method_id, ReturnType::Object, &[])
+                        .and_then(JValueGen::l)
+                        .map(Into::into)?
+                };
+
+                let string = unsafe {
+                    cp.get_string_unchecked(&class_name)
+                        .map(Into::<String>::into)
+                };
+
+                unsafe {
+                    cp.pop_local_frame(&JObject::null())?;
+                }
+
+                string
+            })
+            .map(String::as_str)
+            .map_err(Into::into)
+    }
+
+    fn modifiers(&mut self, cp: &mut ClassPool<'_>) -> Result<u16> {
+        self.modifiers
+            .get_or_try_init(|| {
+                let method_id = cp.get_method_id(Self::CLASS_JNI_CP, "getModifiers", "()I")?;
+
+                unsafe {
+                    cp.call_method_unchecked(
+                        &self.inner,
+                        method_id,
+                        ReturnType::Primitive(Primitive::Int),
+                        &[],
+                    )
+                    .and_then(JValueOwned::i)
+                    .map(|modifiers| modifiers as u16)
+                }
+            })
+            .copied()
+            .map_err(Into::into)
+    }
+
+    fn modifiers_string(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        let modifiers = self.modifiers(cp)?;
+
+        cp.with_local_frame(1, |env| {
+            let modifiers_string: JString = env
+                .call_static_method(
+                    Self::MODIFIER_JNI_CP,
+                    "toString",
+                    "(I)Ljava/lang/String;",
+                    &[JValue::Int(modifiers as i32)],
+                )
+                .and_then(JValueGen::l)?
+                .into();
+
+            unsafe {
+                env.get_string_unchecked(&modifiers_string)
+                    .map(Into::<String>::into)
+                    .map_err(Into::into)
+            }
+        })
+    }
+
+    fn interfaces(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Arc<Mutex<Self>>>> {
+        // Some interfaces might be freed from class pool since they were cached, in that
+        // case fetches all interfaces again
+        if let Some(interfaces) = self.interfaces.get().and_then(|interfaces| {
+            interfaces
+                .iter()
+                .map(Weak::upgrade)
+                .collect::<Option<Vec<_>>>()
+        }) {
+            return Ok(interfaces);
+        }
+
+        cp.push_local_frame(1)?;
+        let method_id =
+            cp.get_method_id(Self::CLASS_JNI_CP, "getInterfaces", "()[Ljava/lang/Class;")?;
+        let interface_arr: JObjectArray = unsafe {
+            cp.call_method_unchecked(&self.inner, method_id, ReturnType::Array, &[])
+                .and_then(JValueGen::l)?
+                .into()
+        };
+        let interfaces_len = cp.get_array_length(&interface_arr)?;
+        let mut interfaces = Vec::with_capacity(interfaces_len as usize);
+
+        for i in 0..interfaces_len {
+            let interface_class = cp.get_object_array_element(&interface_arr, i)?.into();
+            let interface_class = cp.fetch_class_from_jclass(&interface_class, None)?;
+
+            interfaces.push(interface_class);
+        }
+
+        unsafe {
+            cp.pop_local_frame(&JObject::null())?;
+        }
+
+        self.interfaces = OnceCell::with_value(interfaces.iter().map(Arc::downgrade).collect());
+
+        Ok(interfaces)
+    }
+
+    fn interface_summaries(&self, cp: &mut ClassPool<'_>) -> Result<Vec<ClassSummary>> {
+        cp.with_local_frame(2, |env| {
+            let interface_arr: JObjectArray = env
+                .call_method(&self.inner, "getInterfaces", "()[Ljava/lang/Class;", &[])
+                .and_then(JValueGen::l)?
+                .into();
+            let interfaces_len = env.get_array_length(&interface_arr)?;
+            let mut summaries = Vec::with_capacity(interfaces_len as usize);
+
+            for i in 0..interfaces_len {
+                let interface_class: JClass =
+                    env.get_object_array_element(&interface_arr, i)?.into();
+                let interface_class = env.auto_local(interface_class);
+                let name = env.class_name(&*interface_class)?;
+                let modifiers = env
+                    .call_method(&*interface_class, "getModifiers", "()I", &[])
+                    .and_then(JValueGen::i)? as u16;
+
+                summaries.push(ClassSummary { name, modifiers });
+            }
+
+            Ok(summaries)
+        })
+    }
+
+    fn module_name(&self, cp: &mut ClassPool<'_>) -> Result<Option<String>> {
+        cp.with_local_frame(2, |env| {
+            let module = env
+                .call_method(&self.inner, "getModule", "()Ljava/lang/Module;", &[])
+                .and_then(JValueGen::l)?;
+            let module_name: JString = env
+                .call_method(&module, "getName", "()Ljava/lang/String;", &[])
+                .and_then(JValueGen::l)?
+                .into();
+
+            if module_name.is_null() {
+                return Ok(None);
+            }
+
+            env.get_string(&module_name)
+                .map(|module_name| Some(module_name.into()))
+                .map_err(Into::into)
+        })
+    }
+
+    fn resource_bytes(&self, cp: &mut ClassPool<'_>, name: &str) -> Result<Option<Vec<u8>>> {
+        const BUFFER_SIZE: i32 = 8192;
+
+        let result = cp.with_local_frame(5, |env| -> jni::errors::Result<_> {
+            let name = env.new_string(name)?;
+            let stream = env
+                .call_method(
+                    &self.inner,
+                    "getResourceAsStream",
+                    "(Ljava/lang/String;)Ljava/io/InputStream;",
+                    &[(&name).into()],
+                )
+                .and_then(JValueGen::l)?;
+
+            if stream.is_null() {
+                return Ok(None);
+            }
+
+            let buffer = env.new_byte_array(BUFFER_SIZE)?;
+            let mut chunk = vec![0; BUFFER_SIZE as usize];
+            let mut bytes = Vec::new();
+            let read_result = loop {
+                let read_len = match env
+                    .call_method(&stream, "read", "([B)I", &[(&buffer).into()])
+                    .and_then(JValueGen::i)
+                {
+                    Ok(read_len) if read_len < 0 => break Ok(()),
+                    Ok(read_len) => read_len,
+                    Err(err) => break Err(err),
+                };
+                let chunk = &mut chunk[..read_len as usize];
+
+                if let Err(err) = env.get_byte_array_region(&buffer, 0, chunk) {
+                    break Err(err);
+                }
+
+                bytes.extend(chunk.iter().map(|byte| *byte as u8));
+            };
+
+            // Stream is closed even if reading failed, the pending exception thrown while
+            // reading is set aside meanwhile and takes precedence over the one from closing
+            let read_exception = env.exception_occurred()?;
+
+            env.exception_clear()?;
+
+            let close_result = env.call_method(&stream, "close", "()V", &[]);
+
+            if !read_exception.is_null() {
+                env.exception_clear()?;
+                env.throw(read_exception)?;
+            }
+
+            read_result?;
+            close_result?;
+
+            Ok(Some(bytes))
+        });
+
+        cp.catch_java_exception(result)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, cp), fields(class = %self))
+    )]
+    fn methods(&self, cp: &mut ClassPool<'_>, getter_name: &str) -> Result<Vec<Method>> {
+        let method_arr: JObjectArray = cp
+            .call_method(
+                &self.inner,
+                getter_name,
+                "()[Ljava/lang/reflect/Method;",
+                &[],
+            )
+            .and_then(JValueGen::l)?
+            .into();
+        let method_arr = cp.auto_local(method_arr);
+
+        Method::from_array(cp, &method_arr)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, cp), fields(class = %self))
+    )]
+    fn declared_fields(&self, cp: &mut ClassPool<'_>) -> Result<Vec<Field>> {
+        let field_arr: JObjectArray = cp
+            .call_method(
+                &self.inner,
+                "getDeclaredFields",
+                "()[Ljava/lang/reflect/Field;",
+                &[],
+            )
+            .and_then(JValueGen::l)?
+            .into();
+        let field_arr = cp.auto_local(field_arr);
+
+        Field::from_array(cp, &field_arr)
+    }
+
+    fn declared_annotation_types(&self, cp: &mut ClassPool<'_>) -> Result<Vec<Arc<Mutex<Self>>>> {
+        cp.push_local_frame(1)?;
+        let annotation_arr: JObjectArray = cp
+            .call_method(
+                &self.inner,
+                "getDeclaredAnnotations",
+                "()[Ljava/lang/annotation/Annotation;",
+                &[],
+            )
+            .and_then(JValueGen::l)?
+            .into();
+        let annotations_len = cp.get_array_length(&annotation_arr)?;
+        let mut annotation_types = Vec::with_capacity(annotations_len as usize);
+
+        for i in 0..annotations_len {
+            let annotation = cp.get_object_array_element(&annotation_arr, i)?;
+            let annotation_type = cp
+                .call_method(&annotation, "annotationType", "()Ljava/lang/Class;", &[])
+                .and_then(JValueGen::l)?
+                .into();
+            let annotation_type = cp.fetch_class_from_jclass(&annotation_type, None)?;
+
+            annotation_types.push(annotation_type);
+        }
+
+        unsafe {
+            cp.pop_local_frame(&JObject::null())?;
+        }
+
+        Ok(annotation_types)
+    }
+
+    fn identity_hash(&mut self, cp: &mut ClassPool<'_>) -> Result<i32> {
+        let inner = &self.inner;
+
+        self.identity_hash
+            .get_or_try_init(|| {
+                cp.call_static_method(
+                    Self::SYSTEM_JNI_CP,
+                    "identityHashCode",
+                    "(Ljava/lang/Object;)I",
+                    &[inner.into()],
+                )
+                .and_then(JValueGen::i)
+            })
+            .copied()
+            .map_err(Into::into)
+    }
+
+    fn is_assignable_from(&mut self, cp: &mut ClassPool<'_>, other: &mut Self) -> Result<bool> {
+        let key = other.identity_hash(cp)?;
+
+        for (other_ref, is_assignable) in self.assignable_from.get(&key).into_iter().flatten() {
+            // SAFETY: Weak reference is only borrowed as a plain object for comparison
+            let other_obj = unsafe { JObject::from_raw(other_ref.as_raw()) };
+
+            if cp.is_same_object(&other_obj, &other.inner)? {
+                return Ok(*is_assignable);
+            }
+        }
+
+        let is_assignable = self.fetch_is_assignable_from(cp, other)?;
+
+        if let Some(other_ref) = cp.new_weak_ref(&other.inner)? {
+            self.assignable_from
+                .entry(key)
+                .or_default()
+                .push((other_ref, is_assignable));
+        }
+
+        Ok(is_assignable)
+    }
+
+    /// Drops memoized results of `is_assignable_from` whose other class has been unloaded.
+    pub(crate) fn prune_assignable_from(&mut self, env: &JNIEnv<'_>) {
+        self.assignable_from.retain(|_, memoized| {
+            memoized.retain(|(other_ref, _)| !other_ref.is_garbage_collected(env).unwrap_or(false));
+            !memoized.is_empty()
+        });
+    }
+
+    fn fetch_is_assignable_from(
+        &mut self,
+        cp: &mut ClassPool<'_>,
+        other: &mut Self,
+    ) -> Result<bool> {
+        if self.is_primitive(cp)? || other.is_primitive(cp)? {
+            return cp
+                .is_same_object(&self.inner, &other.inner)
+                .map_err(Into::into);
+        }
+
+        // FIXME: Should we explore the both classes class hierarchy and so the
+        // whole hierarchy tree can be cached and used later for better performance?
+        let method_id = cp.get_method_id(
+            Self::CLASS_JNI_CP,
+            "isAssignableFrom",
+            "(Ljava/lang/Class;)Z",
+        )?;
+
+        unsafe {
+            cp.call_method_unchecked(
+                &self.inner,
+                method_id,
+                ReturnType::Primitive(Primitive::Boolean),
+                &[Into::<JValue>::into(&other.inner).as_jni()],
+            )
+            .and_then(JValueOwned::z)
+            .map_err(Into::into)
+        }
+    }
+
+    fn static_field<'local>(
+        &self,
+        cp: &mut ClassPool<'local>,
+        name: &str,
+        descriptor: &str,
+    ) -> Result<JValueOwned<'local>> {
+        cp.get_static_field(&self.inner, name, descriptor)
+            .map_err(Into::into)
+    }
+
+    fn cast(&self, cp: &mut ClassPool<'_>, obj: &JObject<'_>) -> Result<GlobalRef> {
+        let result = cp
+            .call_method(
+                &self.inner,
+                "cast",
+                "(Ljava/lang/Object;)Ljava/lang/Object;",
+                &[obj.into()],
+            )
+            .and_then(JValueGen::l);
+        let casted_obj = cp.catch_java_exception(result)?;
+        let casted_obj = cp.auto_local(casted_obj);
+
+        cp.new_global_ref(&*casted_obj).map_err(Into::into)
+    }
+
+    fn descriptor(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        let class_name = self.name(cp)?;
+
+        Ok(
+            if let Some(desc) = PRIMITIVE_TYPES_TO_DESC.get(&class_name) {
+                desc.to_string()
+            } else if class_name.starts_with('[') {
+                class_name.replace('.', "/")
+            } else {
+                format!("L{};", class_name.replace('.', "/"))
+            },
+        )
+    }
+
+    fn is_primitive(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.name(cp)
+            .map(|class_name| PRIMITIVE_TYPES_TO_DESC.contains_key(&class_name))
+    }
+
+    fn type_parameter_names(&self, cp: &mut ClassPool<'_>) -> Result<Vec<String>> {
+        cp.with_local_frame(4, |env| {
+            let type_parameter_arr: JObjectArray = env
+                .call_method(
+                    &self.inner,
+                    "getTypeParameters",
+                    "()[Ljava/lang/reflect/TypeVariable;",
+                    &[],
+                )
+                .and_then(JValueGen::l)?
+                .into();
+            let type_parameters_len = env.get_array_length(&type_parameter_arr)?;
+            let mut type_parameter_names = Vec::with_capacity(type_parameters_len as usize);
+
+            for i in 0..type_parameters_len {
+                let type_parameter = env.get_object_array_element(&type_parameter_arr, i)?;
+                let type_parameter = env.auto_local(type_parameter);
+                let type_parameter_name: JString = env
+                    .call_method(&type_parameter, "getName", "()Ljava/lang/String;", &[])
+                    .and_then(JValueGen::l)?
+                    .into();
+                let type_parameter_name = env.auto_local(type_parameter_name);
+
+                type_parameter_names.push(env.get_string(&type_parameter_name)?.into());
+            }
+
+            Ok(type_parameter_names)
+        })
+    }
+
+    fn is_deprecated(&self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        cp.with_local_frame(1, |env| {
+            let deprecated_class = env.find_class("java/lang/Deprecated")?;
+
+            env.call_method(
+                &self.inner,
+                "isAnnotationPresent",
+                "(Ljava/lang/Class;)Z",
+                &[(&deprecated_class).into()],
+            )
+            .and_then(JValueGen::z)
+            .map_err(Into::into)
+        })
+    }
+
+    fn is_interface(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.modifiers(cp).map(Modifiers::is_interface_bits)
+    }
+
+    fn is_annotation(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.modifiers(cp).map(Modifiers::is_annotation_bits)
+    }
+
+    fn is_synthetic(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        self.modifiers(cp).map(Modifiers::is_synthetic_bits)
+    }
+
+    fn is_inner_class(&mut self, cp: &mut ClassPool<'_>) -> Result<bool> {
+        let is_member_class = cp
+            .call_method(&self.inner, "isMemberClass", "()Z", &[])
+            .and_then(JValueGen::z)?;
+
+        Ok(is_member_class && !Modifiers::is_static_bits(self.modifiers(cp)?))
+    }
+
+    fn kind(&mut self, cp: &mut ClassPool<'_>) -> Result<ClassKind> {
+        let class_name = self.name(cp)?;
+
+        if class_name.starts_with('[') {
+            return Ok(ClassKind::Array);
+        }
+
+        if self.is_primitive(cp)? {
+            return Ok(ClassKind::Primitive);
+        }
+
+        let modifiers = self.modifiers(cp)?;
+
+        if Modifiers::is_annotation_bits(modifiers) {
+            return Ok(ClassKind::Annotation);
+        }
+
+        if Modifiers::is_interface_bits(modifiers) {
+            return Ok(ClassKind::Interface);
+        }
+
+        let superclass_name = match self.superclass(cp)? {
+            Some(superclass) => Some(superclass.lock()?.name(cp)?),
+            None => None,
+        };
+
+        match superclass_name.as_deref() {
+            Some("java.lang.Enum") if Modifiers::is_enum_bits(modifiers) => Ok(ClassKind::Enum),
+            Some("java.lang.Record") => Ok(ClassKind::Record),
+            _ => Ok(ClassKind::Class),
+        }
+    }
+}
+
+impl Display for ClassInternal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.class_name.get() {
+            Some(class_name) => write!(f, "Class({class_name})"),
+            // Class path is in JNI form, converts it into `java.lang.Class#getName`'s form
+            None => write!(f, "Class({})", self.class_path.replace('/', ".")),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "invocation"))]
 mod test {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+        sync::Arc,
+    };
+
+    use jni::objects::{JClass, JString, JValue, JValueGen};
     use rstest::rstest;
 
-    use crate::{class::Class, classpool::ClassPool, errors::HierResult};
+    use crate::{
+        class::{Class, ClassKind, ClassSummary},
+        classpath::ClassPath,
+        classpool::ClassPool,
+        errors::{HierError, HierResult},
+        fixtures::{fixture_class_loader, lookup_fixture},
+        modifiers::Modifiers,
+    };
+
+    #[test]
+    fn test_lookup_caching() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let _class1 = cp.lookup_class("java.lang.Object")?;
+
+        assert_eq!(cp.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interfaces_freed_by_clear() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let interfaces = class
+            .interfaces(&mut cp)?
+            .iter()
+            .map(|interface| Arc::downgrade(interface))
+            .collect::<Vec<_>>();
+
+        assert!(!interfaces.is_empty());
+        assert!(interfaces
+            .iter()
+            .all(|interface| interface.strong_count() == 1));
+
+        cp.clear();
+
+        assert!(interfaces
+            .iter()
+            .all(|interface| interface.strong_count() == 0));
+
+        let mut interfaces = class.interfaces(&mut cp)?;
+
+        assert_eq!(interfaces[0].name(&mut cp)?, "java.lang.Comparable");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_superclass() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let superclass = class.superclass(&mut cp)?;
+
+        assert!(superclass.is_some());
+
+        let mut superclass = superclass.unwrap();
+
+        assert_eq!(superclass.name(&mut cp)?, "java.lang.Number");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Integer", Some("java.lang.Number"))]
+    #[case("java.lang.Object", None)]
+    #[case("java.util.List", None)]
+    fn test_superclass_name(
+        #[case] class_path: &'static str,
+        #[case] superclass_name: Option<&'static str>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.superclass_name(&mut cp)?.as_deref(), superclass_name);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.util.AbstractList", true)]
+    #[case("java.lang.Object", true)]
+    #[case("java.lang.Iterable", true)]
+    #[case("java.util.ArrayList", false)]
+    #[case("java.lang.Thread", false)]
+    fn test_hierarchy_contains(
+        #[case] name: &'static str,
+        #[case] contains: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+
+        assert_eq!(class.hierarchy_contains(&mut cp, name)?, contains);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_throwable() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.RuntimeException")?;
+        let throwable_class = class
+            .as_throwable(&mut cp)?
+            .expect("RuntimeException should be throwable");
+        let throwable = throwable_class.new_instance_with_message(&mut cp, "boom")?;
+        let message: JString = cp
+            .call_method(&throwable, "getMessage", "()Ljava/lang/String;", &[])?
+            .l()?
+            .into();
+
+        assert!(cp.is_instance_of(&throwable, "java/lang/RuntimeException")?);
+        assert_eq!(String::from(cp.get_string(&message)?), "boom");
+        assert!(cp
+            .lookup_class("java.lang.String")?
+            .as_throwable(&mut cp)?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.Outer$Inner", true)]
+    #[case("fixtures.Outer$StaticNested", false)]
+    #[case("fixtures.Outer", false)]
+    fn test_is_inner_class(
+        #[case] class_path: &'static str,
+        #[case] is_inner_class: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class_path)?;
+
+        assert_eq!(class.is_inner_class(&mut cp)?, is_inner_class);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.Outer", "fixtures", true)]
+    #[case("fixtures.Outer", "com.example", true)]
+    #[case("fixtures.Outer$Inner", "com.example", true)]
+    #[case("fixtures.PackagePrivate", "fixtures", true)]
+    #[case("fixtures.PackagePrivate", "com.example", false)]
+    #[case("fixtures.PackagePrivate", "", false)]
+    #[case("fixtures.PackagePrivate$Nested", "fixtures", true)]
+    #[case("fixtures.PackagePrivate$Nested", "com.example", false)]
+    fn test_is_accessible_from(
+        #[case] class_path: &'static str,
+        #[case] from_package: &'static str,
+        #[case] is_accessible: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class_path)?;
+
+        assert_eq!(
+            class.is_accessible_from(&mut cp, from_package)?,
+            is_accessible
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.String", "com.example")]
+    #[case("java.util.Map$Entry", "")]
+    #[case("java.lang.String[]", "com.example")]
+    fn test_public_is_accessible_from(
+        #[case] class_path: &'static str,
+        #[case] from_package: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert!(class.is_accessible_from(&mut cp, from_package)?);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.Outer", false)]
+    #[case("fixtures.Outer$StaticNested", false)]
+    #[case("fixtures.Outer$Inner", true)]
+    fn test_outer_instance_field(
+        #[case] class_path: &'static str,
+        #[case] has_field: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class_path)?;
+
+        match class.outer_instance_field(&mut cp)? {
+            Some(mut field) => {
+                assert!(has_field);
+                assert_eq!(field.name(&mut cp)?, "this$0");
+                assert!(Modifiers::is_synthetic_bits(field.modifiers(&mut cp)?));
+                assert_eq!(field.field_type(&mut cp)?.name(&mut cp)?, "fixtures.Outer");
+            }
+            None => assert!(!has_field),
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.FieldDerived", Some(("fixtures.FieldBase", "int")))]
+    #[case("fixtures.FieldShadowed", Some(("fixtures.FieldShadowed", "long")))]
+    #[case("fixtures.Outer", None)]
+    fn test_resolve_field(
+        #[case] class_path: &'static str,
+        #[case] resolved: Option<(&'static str, &'static str)>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class_path)?;
+        let resolved_names = match class.resolve_field(&mut cp, "value")? {
+            Some((mut declaring_class, mut field)) => Some((
+                declaring_class.name(&mut cp)?,
+                field.field_type(&mut cp)?.name(&mut cp)?,
+            )),
+            None => None,
+        };
+
+        assert_eq!(
+            resolved_names
+                .as_ref()
+                .map(|(class_name, type_name)| (class_name.as_str(), type_name.as_str())),
+            resolved
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_summaries() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let summaries = class.interface_summaries(&mut cp)?;
+
+        assert_eq!(cp.len(), 1);
+
+        let expected = class
+            .interfaces(&mut cp)?
+            .iter_mut()
+            .map(|interface| {
+                Ok(ClassSummary {
+                    name: interface.name(&mut cp)?,
+                    modifiers: interface.modifiers(&mut cp)?,
+                })
+            })
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert!(!summaries.is_empty());
+        assert_eq!(summaries, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_ancestor() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let abstract_ancestor = class.find_ancestor(&mut cp, |cp, ancestor| {
+            ancestor.modifiers(cp).map(Modifiers::is_abstract_bits)
+        })?;
+
+        assert_eq!(
+            abstract_ancestor
+                .map(|mut ancestor| ancestor.name(&mut cp))
+                .transpose()?
+                .as_deref(),
+            Some("java.lang.Number")
+        );
+
+        let thread_ancestor = class.find_ancestor(&mut cp, |cp, ancestor| {
+            ancestor.name(cp).map(|name| name == "java.lang.Thread")
+        })?;
+
+        assert!(thread_ancestor.is_none());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.util.ArrayList", "java.util.ArrayList", true)]
+    #[case("java.lang.Integer", "java.lang.Long", true)]
+    #[case("java.util.ArrayList", "java.lang.Thread", false)]
+    #[case("java.lang.Integer", "java.lang.Number", false)]
+    fn test_hierarchy_equals(
+        #[case] class_path: &'static str,
+        #[case] other_class_path: &'static str,
+        #[case] hierarchy_equals: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let mut other = cp.lookup_class(other_class_path)?;
+
+        assert_eq!(
+            class.hierarchy_equals(&mut cp, &mut other)?,
+            hierarchy_equals
+        );
+        assert_eq!(
+            other.hierarchy_equals(&mut cp, &mut class)?,
+            hierarchy_equals
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_depths() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.ArrayList")?;
+        let interface_depths = class
+            .interface_depths(&mut cp)?
+            .into_iter()
+            .map(|(mut interface, depth)| Ok((interface.name(&mut cp)?, depth)))
+            .collect::<HierResult<HashMap<_, _>>>()?;
+
+        assert_eq!(interface_depths["java.util.List"], 1);
+        assert_eq!(interface_depths["java.util.Collection"], 2);
+        assert_eq!(interface_depths["java.lang.Iterable"], 3);
+        assert_eq!(interface_depths.len(), class.all_interfaces(&mut cp)?.len());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Integer", true)]
+    #[case("java.lang.Boolean", true)]
+    #[case("java.lang.Void", true)]
+    #[case("java.lang.String", false)]
+    #[case("java.lang.Number", false)]
+    #[case("int", false)]
+    #[case("java.lang.Integer[]", false)]
+    fn test_is_wrapper_type(
+        #[case] class_path: &'static str,
+        #[case] is_wrapper_type: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.is_wrapper_type(&mut cp)?, is_wrapper_type);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Object", Some("Object.java"))]
+    #[case("java.util.Map$Entry", Some("Map.java"))]
+    #[case("int", None)]
+    #[case("java.lang.String[]", None)]
+    fn test_source_file(
+        #[case] class_path: &'static str,
+        #[case] source_file: Option<&'static str>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.source_file(&mut cp)?.as_deref(), source_file);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture_source_file() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, "fixtures.Outer$Inner")?;
+
+        assert_eq!(class.source_file(&mut cp)?.as_deref(), Some("Outer.java"));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Iterable", "java.util.Collection", true)]
+    #[case("java.lang.Iterable", "java.util.List", true)]
+    #[case("java.util.Collection", "java.lang.Iterable", false)]
+    #[case("java.util.List", "java.util.List", false)]
+    #[case("java.util.List", "java.util.ArrayList", false)]
+    #[case("java.lang.Object", "java.util.List", false)]
+    fn test_is_superinterface_of(
+        #[case] class_path: &'static str,
+        #[case] other_class_path: &'static str,
+        #[case] is_superinterface: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let mut other = cp.lookup_class(other_class_path)?;
+
+        assert_eq!(
+            class.is_superinterface_of(&mut cp, &mut other)?,
+            is_superinterface
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.util.function.Function", Some("apply"))]
+    #[case("java.util.Comparator", Some("compare"))]
+    #[case("java.lang.Runnable", Some("run"))]
+    #[case("java.util.List", None)]
+    #[case("java.lang.Override", None)]
+    #[case("java.lang.Thread", None)]
+    fn test_functional_method(
+        #[case] class_path: &'static str,
+        #[case] method_name: Option<&'static str>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let functional_method_name = class
+            .functional_method(&mut cp)?
+            .map(|mut method| method.name(&mut cp))
+            .transpose()?;
+
+        assert_eq!(functional_method_name.as_deref(), method_name);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Integer", "valueOf", &["java.lang.String"], Some(&["java.lang.String"][..]))]
+    #[case("java.lang.Integer", "valueOf", &["int"], Some(&["int"][..]))]
+    #[case("java.lang.Integer", "valueOf", &["java.lang.String", "int"], Some(&["java.lang.String", "int"][..]))]
+    #[case("java.lang.Integer", "valueOf", &["long"], None)]
+    #[case("java.lang.StringBuilder", "append", &["java.lang.String"], Some(&["java.lang.String"][..]))]
+    #[case("java.lang.StringBuilder", "append", &["java.lang.Thread"], Some(&["java.lang.Object"][..]))]
+    fn test_most_specific_method(
+        #[case] class_path: &'static str,
+        #[case] name: &'static str,
+        #[case] arg_class_paths: &[&'static str],
+        #[case] parameter_names: Option<&[&'static str]>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let arg_types = arg_class_paths
+            .iter()
+            .map(|arg_class_path| cp.lookup_class(*arg_class_path))
+            .collect::<HierResult<Vec<_>>>()?;
+        let resolved_parameter_names =
+            match class.most_specific_method(&mut cp, name, &arg_types)? {
+                Some(mut method) => Some(
+                    method
+                        .parameter_types(&mut cp)?
+                        .iter_mut()
+                        .map(|parameter_type| parameter_type.name(&mut cp))
+                        .collect::<HierResult<Vec<_>>>()?,
+                ),
+                None => None,
+            };
+
+        match parameter_names {
+            Some(parameter_names) => assert_eq!(
+                resolved_parameter_names.expect("method should be resolved"),
+                parameter_names
+            ),
+            None => assert!(resolved_parameter_names.is_none()),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_name() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let name_hash = |name: &str| {
+            let mut hasher = DefaultHasher::new();
+            name.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(
+            class.with_name(&mut cp, name_hash)?,
+            name_hash("java.lang.Integer")
+        );
+        assert_eq!(
+            class.with_name(&mut cp, str::len)?,
+            "java.lang.Integer".len()
+        );
+
+        Ok(())
+    }
 
     #[test]
-    fn test_lookup_caching() -> HierResult<()> {
+    fn test_ancestors() -> HierResult<()> {
         let mut cp = ClassPool::from_permanent_env()?;
-        let _class1 = cp.lookup_class("java.lang.Object")?;
+        let number_class = cp.lookup_class("java.lang.Number")?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let direct_superclasses = class
+            .ancestors(&mut cp)
+            .take(1)
+            .collect::<HierResult<Vec<_>>>()?;
 
-        assert_eq!(cp.len(), 1);
+        assert_eq!(direct_superclasses.len(), 1);
+        assert!(Arc::ptr_eq(&direct_superclasses[0], &number_class));
+
+        let found = class.ancestors(&mut cp).find(
+            |ancestor| matches!(ancestor, Ok(ancestor) if Arc::ptr_eq(ancestor, &number_class)),
+        );
+
+        assert!(found.is_some());
+        assert_eq!(class.ancestors(&mut cp).count(), 2);
 
         Ok(())
     }
 
     #[test]
-    fn test_superclass() -> HierResult<()> {
+    fn test_enclosing_chain() -> HierResult<()> {
         let mut cp = ClassPool::from_permanent_env()?;
-        let mut class = cp.lookup_class("java.lang.Integer")?;
-        let superclass = class.superclass(&mut cp)?;
+        let mut class = cp.lookup_class("java.lang.ProcessBuilder$Redirect$Type")?;
+        let enclosing_names = class
+            .enclosing_chain(&mut cp)?
+            .iter_mut()
+            .map(|enclosing_class| enclosing_class.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
 
-        assert!(superclass.is_some());
+        assert_eq!(
+            enclosing_names,
+            vec![
+                "java.lang.ProcessBuilder$Redirect",
+                "java.lang.ProcessBuilder"
+            ]
+        );
 
-        let mut superclass = superclass.unwrap();
+        let mut class = cp.lookup_class("java.lang.ProcessBuilder")?;
 
-        assert_eq!(superclass.name(&mut cp)?, "java.lang.Number");
+        assert!(class.enclosing_chain(&mut cp)?.is_empty());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.AnnotatedBase", vec!["fixtures.InheritedMarker"])]
+    #[case("fixtures.AnnotatedDerived", vec!["fixtures.Marker"])]
+    fn test_declared_annotation_types(
+        #[case] class: &'static str,
+        #[case] annotation_type_names: Vec<&'static str>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class)?;
+        let declared_annotation_type_names = class
+            .declared_annotation_types(&mut cp)?
+            .iter_mut()
+            .map(|annotation_type| annotation_type.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert_eq!(declared_annotation_type_names, annotation_type_names);
 
         Ok(())
     }
@@ -400,6 +2523,412 @@ mod test {
         Ok(())
     }
 
+    /// Flips memoized `is_assignable_from` results of given [Class], so results answered by
+    /// the memo are told apart from ones answered by JNI.
+    fn flip_memoized(class: &Class) -> HierResult<()> {
+        for memoized in class.lock()?.assignable_from.values_mut() {
+            for (_, is_assignable) in memoized {
+                *is_assignable = !*is_assignable;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn memoized_len(class: &Class) -> HierResult<usize> {
+        Ok(class.lock()?.assignable_from.values().map(Vec::len).sum())
+    }
+
+    #[test]
+    fn test_is_assignable_from_memoized() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut number = cp.lookup_class("java.lang.Number")?;
+        let integer = cp.lookup_class("java.lang.Integer")?;
+
+        assert!(number.is_assignable_from(&mut cp, &integer)?);
+        assert_eq!(memoized_len(&number)?, 1);
+
+        flip_memoized(&number)?;
+
+        assert!(!number.is_assignable_from(&mut cp, &integer)?);
+
+        let string = cp.lookup_class("java.lang.String")?;
+
+        assert!(!number.is_assignable_from(&mut cp, &string)?);
+        assert_eq!(memoized_len(&number)?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_assignable_from_memo_key() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut number = cp.lookup_class("java.lang.Number")?;
+        let integer = cp.lookup_class("java.lang.Integer")?;
+
+        assert!(number.is_assignable_from(&mut cp, &integer)?);
+
+        flip_memoized(&number)?;
+
+        // The same `java.lang.Class` looked up from another pool hits the memo
+        let mut other_cp = ClassPool::from_permanent_env()?;
+        let other_integer = other_cp.lookup_class("java.lang.Integer")?;
+
+        assert!(!Arc::ptr_eq(&integer, &other_integer));
+        assert!(!number.is_assignable_from(&mut cp, &other_integer)?);
+
+        // So does a class fetched again after it's freed, since it's not unloaded
+        assert!(cp.free_class("java.lang.Integer"));
+
+        let refetched_integer = cp.lookup_class("java.lang.Integer")?;
+
+        assert!(!Arc::ptr_eq(&integer, &refetched_integer));
+        assert!(!number.is_assignable_from(&mut cp, &refetched_integer)?);
+        assert_eq!(memoized_len(&number)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_index() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, "fixtures.DiamondImpl")?;
+        let diamond_a = lookup_fixture(&mut cp, "fixtures.DiamondA")?;
+        let diamond_b = lookup_fixture(&mut cp, "fixtures.DiamondB")?;
+        let diamond_c = lookup_fixture(&mut cp, "fixtures.DiamondC")?;
+
+        assert_eq!(class.interface_index(&mut cp, &diamond_c)?, Some(0));
+        assert_eq!(class.interface_index(&mut cp, &diamond_b)?, Some(1));
+        assert_eq!(class.interface_index(&mut cp, &diamond_a)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_same_class() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut other_cp = ClassPool::from_permanent_env()?;
+        let mut object_class = cp.lookup_class("java.lang.Object")?;
+        let other_object_class = other_cp.lookup_class("java.lang.Object")?;
+        let string_class = cp.lookup_class("java.lang.String")?;
+
+        assert!(!Arc::ptr_eq(&object_class, &other_object_class));
+        assert!(object_class.is_same_class(&mut cp, &other_object_class)?);
+        assert!(!object_class.is_same_class(&mut cp, &string_class)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resource_bytes() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Object")?;
+        let bytes = class.resource_bytes(&mut cp, "Object.class")?;
+
+        assert!(bytes.is_some_and(|bytes| bytes.starts_with(&[0xCA, 0xFE, 0xBA, 0xBE])));
+        assert_eq!(class.resource_bytes(&mut cp, "NonExistent.txt")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resource_bytes_read_failure() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let loader_class = lookup_fixture(&mut cp, "fixtures.FailingResourceLoader")?;
+        let parent_loader = fixture_class_loader(&mut cp)?;
+        let loader_class_obj = loader_class.lock()?.inner.clone();
+        let loader = cp.new_object(
+            <&JClass>::from(loader_class_obj.as_obj()),
+            "(Ljava/lang/ClassLoader;)V",
+            &[parent_loader.as_obj().into()],
+        )?;
+        let mut class = cp.lookup_class_with_loader("fixtures.DeprecatedClass", &loader)?;
+
+        assert!(matches!(
+            class.resource_bytes(&mut cp, "DeprecatedClass.class"),
+            Err(HierError::JavaException(class_name, Some(message)))
+                if class_name == "java.io.IOException" && message == "broken stream"
+        ));
+        assert!(!cp.exception_check()?);
+
+        let is_closed = cp
+            .get_static_field(<&JClass>::from(loader_class_obj.as_obj()), "closed", "Z")
+            .and_then(JValueGen::z)?;
+
+        assert!(is_closed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut number_class = cp.lookup_class("java.lang.Number")?;
+        let mut string_class = cp.lookup_class("java.lang.String")?;
+        let integer = cp.new_object("java/lang/Integer", "(I)V", &[JValue::Int(1)])?;
+        let casted_integer = number_class.cast(&mut cp, &integer)?;
+
+        assert!(cp.is_same_object(&integer, &casted_integer)?);
+        assert!(matches!(
+            string_class.cast(&mut cp, &integer),
+            Err(HierError::JavaException(class_name, Some(_))) if class_name == "java.lang.ClassCastException"
+        ));
+        assert!(!cp.exception_check()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interfaces_recursive_edges() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, "fixtures.DiamondD")?;
+        let edges = class
+            .interfaces_recursive_edges(&mut cp)?
+            .into_iter()
+            .map(|(mut from, mut to)| Ok((from.name(&mut cp)?, to.name(&mut cp)?)))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert_eq!(
+            edges,
+            [
+                ("fixtures.DiamondD", "fixtures.DiamondB"),
+                ("fixtures.DiamondD", "fixtures.DiamondC"),
+                ("fixtures.DiamondB", "fixtures.DiamondA"),
+                ("fixtures.DiamondC", "fixtures.DiamondA"),
+            ]
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("int", "I")]
+    #[case("java.lang.String", "Ljava/lang/String;")]
+    #[case("int[]", "[I")]
+    #[case("java.lang.String[][]", "[[Ljava/lang/String;")]
+    fn test_descriptor(
+        #[case] class_path: &'static str,
+        #[case] descriptor: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.descriptor(&mut cp)?, descriptor);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Object", "Class(java.lang.Object)")]
+    #[case("java.util.Map$Entry", "Class(java.util.Map$Entry)")]
+    #[case("int", "Class(int)")]
+    #[case("java.lang.String[]", "Class([Ljava.lang.String;)")]
+    fn test_display(
+        #[case] class_path: &'static str,
+        #[case] display: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.to_string(), display);
+
+        class.name(&mut cp)?;
+
+        assert_eq!(class.to_string(), display);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_public_methods() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let mut declaring_class_names = HashMap::new();
+
+        for mut method in class.public_methods(&mut cp)? {
+            let declaring_class_name = method.declaring_class(&mut cp)?.name(&mut cp)?;
+
+            declaring_class_names.insert(method.name(&mut cp)?, declaring_class_name);
+        }
+
+        assert_eq!(
+            declaring_class_names.get("toString").map(String::as_str),
+            Some("java.lang.Integer")
+        );
+        assert_eq!(
+            declaring_class_names.get("getClass").map(String::as_str),
+            Some("java.lang.Object")
+        );
+
+        let declared_method_names = class
+            .declared_methods(&mut cp)?
+            .iter_mut()
+            .map(|method| method.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert!(!declared_method_names.contains(&"getClass".to_string()));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Object", "java.lang.Integer", Some(2))]
+    #[case("java.lang.Integer", "java.lang.Integer", Some(0))]
+    #[case("java.lang.Comparable", "java.lang.Integer", Some(1))]
+    #[case("java.lang.Iterable", "java.util.ArrayList", Some(3))]
+    #[case("java.lang.Object", "java.util.List", Some(1))]
+    #[case("java.lang.Object[]", "java.lang.Integer[]", Some(2))]
+    #[case("java.lang.String", "java.lang.Integer", None)]
+    #[case("java.lang.Integer", "java.lang.Object", None)]
+    fn test_assignability_distance(
+        #[case] class: &'static str,
+        #[case] sub: &'static str,
+        #[case] distance: Option<usize>,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class)?;
+        let mut sub = cp.lookup_class(sub)?;
+
+        assert_eq!(class.assignability_distance(&mut cp, &mut sub)?, distance);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Integer", &["java.lang.Number", "java.lang.Comparable"])]
+    #[case("java.util.List", &["java.util.Collection"])]
+    #[case("java.lang.Object", &[])]
+    fn test_direct_supertypes(
+        #[case] class_path: &'static str,
+        #[case] leading_supertype_names: &[&'static str],
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+        let supertype_names = class
+            .direct_supertypes(&mut cp)?
+            .iter_mut()
+            .map(|supertype| supertype.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert!(supertype_names.starts_with(
+            &leading_supertype_names
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        ));
+
+        if leading_supertype_names.is_empty() {
+            assert!(supertype_names.is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.util.Map$Entry", "java.util.Map$Entry", "java/util/Map$Entry")]
+    #[case("java.lang.String[]", "[Ljava.lang.String;", "[Ljava/lang/String;")]
+    #[case("int[][]", "[[I", "[[I")]
+    #[case("int", "int", "int")]
+    fn test_jni_name(
+        #[case] class_path: &'static str,
+        #[case] name: &'static str,
+        #[case] jni_name: &'static str,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.name(&mut cp)?, name);
+        assert_eq!(class.jni_name(&mut cp)?, jni_name);
+        let cached_class = cp.lookup_class_path(&ClassPath::JNI(jni_name.to_string()))?;
+
+        assert!(Arc::ptr_eq(&class, &cached_class));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("fixtures.DeprecatedClass", true)]
+    #[case("fixtures.Marker", false)]
+    #[case("java.lang.String", false)]
+    fn test_is_deprecated(
+        #[case] class_path: &'static str,
+        #[case] is_deprecated: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, class_path)?;
+
+        assert_eq!(class.is_deprecated(&mut cp)?, is_deprecated);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_methods() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let mut methods = class.find_methods(&mut cp, |cp, method| {
+            Ok(Modifiers::is_static_bits(method.modifiers(cp)?) && method.name(cp)? == "parseInt")
+        })?;
+
+        assert!(!methods.is_empty());
+
+        for method in &mut methods {
+            assert_eq!(method.name(&mut cp)?, "parseInt");
+            assert_eq!(method.return_type(&mut cp)?.name(&mut cp)?, "int");
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.util.List", &["E"])]
+    #[case("java.util.Map", &["K", "V"])]
+    #[case("java.lang.String", &[])]
+    fn test_type_parameter_names(
+        #[case] class_path: &'static str,
+        #[case] type_parameter_names: &[&'static str],
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class_path)?;
+
+        assert_eq!(class.type_parameter_names(&mut cp)?, type_parameter_names);
+        assert_eq!(class.is_generic(&mut cp)?, !type_parameter_names.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modifiers_string() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.String")?;
+
+        assert_eq!(class.modifiers_string(&mut cp)?, "public final");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("int", "int", true)]
+    #[case("int", "long", false)]
+    #[case("long", "int", false)]
+    #[case("int", "java.lang.Integer", false)]
+    #[case("java.lang.Integer", "int", false)]
+    #[case("java.lang.Object", "int", false)]
+    fn test_primitive_is_assignable_from(
+        #[case] class: &'static str,
+        #[case] other: &'static str,
+        #[case] is_assignable: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class)?;
+        let other = cp.lookup_class(other)?;
+
+        assert_eq!(class.is_assignable_from(&mut cp, &other)?, is_assignable);
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_interface() -> HierResult<()> {
         let mut cp = ClassPool::from_permanent_env()?;
@@ -420,6 +2949,70 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_is_not_annotation() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut interface = cp.lookup_class("java.lang.Comparable")?;
+
+        assert!(!interface.is_annotation(&mut cp)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_static_field() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let int_class = cp.lookup_class("int")?;
+        let max_value = class.static_field(&mut cp, "MAX_VALUE", &int_class)?.i()?;
+
+        assert_eq!(max_value, 2147483647);
+
+        let mut class = cp.lookup_class("java.lang.Boolean")?;
+        let boolean_class = class.clone();
+        let true_value = class.static_field(&mut cp, "TRUE", &boolean_class)?.l()?;
+
+        assert!(!true_value.is_null());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.RuntimeException", true, true, false)]
+    #[case("java.lang.StackOverflowError", true, false, true)]
+    #[case("java.lang.Throwable", true, false, false)]
+    #[case("java.lang.String", false, false, false)]
+    fn test_is_throwable(
+        #[case] class: &'static str,
+        #[case] is_throwable: bool,
+        #[case] is_exception: bool,
+        #[case] is_error: bool,
+    ) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class(class)?;
+
+        assert_eq!(class.is_throwable(&mut cp)?, is_throwable);
+        assert_eq!(class.is_exception(&mut cp)?, is_exception);
+        assert_eq!(class.is_error(&mut cp)?, is_error);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("java.lang.Override", ClassKind::Annotation)]
+    #[case("java.util.List", ClassKind::Interface)]
+    #[case("java.lang.Thread$State", ClassKind::Enum)]
+    #[case("int[]", ClassKind::Array)]
+    #[case("int", ClassKind::Primitive)]
+    #[case("java.lang.String", ClassKind::Class)]
+    fn test_kind(#[case] class: &'static str, #[case] kind: ClassKind) -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        assert_eq!(cp.lookup_class(class)?.kind(&mut cp)?, kind);
+
+        Ok(())
+    }
+
     #[rstest]
     #[case("java.lang.Integer", "java.lang.Float", "java.lang.Number")]
     #[case("java.util.EnumMap", "java.util.HashMap", "java.util.AbstractMap")]
@@ -468,6 +3061,27 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(
+        not(any(
+            jvm_v11, jvm_v12, jvm_v13, jvm_v14, jvm_v15, jvm_v16, jvm_v17, jvm_v18, jvm_v19,
+            jvm_v20, jvm_v21, jvm_v22, jvm_v23,
+        )),
+        ignore = "No Java 11+ version provided"
+    )]
+    fn test_module_name() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Object")?;
+
+        assert_eq!(class.module_name(&mut cp)?.as_deref(), Some("java.base"));
+
+        let mut class = lookup_fixture(&mut cp, "fixtures.Marker")?;
+
+        assert_eq!(class.module_name(&mut cp)?, None);
+
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(
         not(any(jvm_v8, jvm_v11, jvm_v17, jvm_v21)),
@@ -0,0 +1,138 @@
+use jni::objects::{GlobalRef, JClass, JObjectArray, JString, JValueGen};
+use once_cell::sync::OnceCell;
+
+use crate::class::Class;
+use crate::classpool::ClassPool;
+use crate::errors::HierResult as Result;
+
+/// A field of a [Class], backed by a `java.lang.reflect.Field` object.
+///
+/// Immutable informations, e.g. name and modifiers, are cached after the first fetch.
+#[derive(Debug, Clone)]
+pub struct Field {
+    inner: GlobalRef,
+    name: OnceCell<String>,
+    modifiers: OnceCell<u16>,
+}
+
+impl Field {
+    /// Creates new [Field] from an [GlobalRef] that stores reference to
+    /// `java.lang.reflect.Field` as internal backend.
+    pub(crate) fn new(field_obj: GlobalRef) -> Self {
+        Self {
+            inner: field_obj,
+            name: OnceCell::new(),
+            modifiers: OnceCell::new(),
+        }
+    }
+
+    /// Collects fields from a `java.lang.reflect.Field[]`.
+    pub(crate) fn from_array(
+        cp: &mut ClassPool<'_>,
+        field_arr: &JObjectArray<'_>,
+    ) -> Result<Vec<Self>> {
+        let fields_len = cp.get_array_length(field_arr)?;
+        let mut fields = Vec::with_capacity(fields_len as usize);
+
+        for i in 0..fields_len {
+            let field = cp.get_object_array_element(field_arr, i)?;
+            let field = cp.auto_local(field);
+
+            fields.push(Self::new(cp.new_global_ref(&*field)?));
+        }
+
+        Ok(fields)
+    }
+
+    /// Fetches field name.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Field#getName`.
+    pub fn name(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        self.name
+            .get_or_try_init(|| {
+                cp.with_local_frame(1, |env| {
+                    let name: JString = env
+                        .call_method(&self.inner, "getName", "()Ljava/lang/String;", &[])
+                        .and_then(JValueGen::l)?
+                        .into();
+
+                    env.get_string(&name).map(Into::into).map_err(Into::into)
+                })
+            })
+            .cloned()
+    }
+
+    /// Returns field's access flags. See [Modifiers](crate::modifiers::Modifiers) for all
+    /// possible modifiers that would OR-ing together.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Field#getModifiers`.
+    pub fn modifiers(&mut self, cp: &mut ClassPool<'_>) -> Result<u16> {
+        self.modifiers
+            .get_or_try_init(|| {
+                cp.call_method(&self.inner, "getModifiers", "()I", &[])
+                    .and_then(JValueGen::i)
+                    .map(|modifiers| modifiers as u16)
+            })
+            .copied()
+            .map_err(Into::into)
+    }
+
+    /// Returns the [Class] which declares this field.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Field#getDeclaringClass`.
+    pub fn declaring_class(&mut self, cp: &mut ClassPool<'_>) -> Result<Class> {
+        self.class_of(cp, "getDeclaringClass")
+    }
+
+    /// Returns the [Class] of this field's type.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Field#getType`.
+    pub fn field_type(&mut self, cp: &mut ClassPool<'_>) -> Result<Class> {
+        self.class_of(cp, "getType")
+    }
+
+    /// Returns class names of annotation types present on this field, annotations are
+    /// only visible if they are retained at runtime.
+    ///
+    /// This function is equivalent to calling `annotationType().getName()` on each
+    /// annotation returned by `java.lang.reflect.Field#getAnnotations`.
+    pub fn annotation_type_names(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<String>> {
+        cp.annotation_type_names(&self.inner)
+    }
+
+    fn class_of(&self, cp: &mut ClassPool<'_>, getter_name: &str) -> Result<Class> {
+        let class: JClass = cp
+            .call_method(&self.inner, getter_name, "()Ljava/lang/Class;", &[])
+            .and_then(JValueGen::l)?
+            .into();
+        let class = cp.auto_local(class);
+
+        cp.fetch_class_from_jclass(&class, None).map(Class::new)
+    }
+}
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use crate::{classpool::ClassPool, errors::HierResult, fixtures::lookup_fixture};
+
+    #[test]
+    fn test_field_info() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = lookup_fixture(&mut cp, "fixtures.AnnotatedMembers")?;
+        let mut fields = class.declared_fields(&mut cp)?;
+
+        assert_eq!(fields.len(), 1);
+
+        let field = &mut fields[0];
+
+        assert_eq!(field.name(&mut cp)?, "value");
+        assert_eq!(field.field_type(&mut cp)?.name(&mut cp)?, "int");
+        assert_eq!(
+            field.declaring_class(&mut cp)?.name(&mut cp)?,
+            "fixtures.AnnotatedMembers"
+        );
+        assert_eq!(field.annotation_type_names(&mut cp)?, ["fixtures.Marker"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use jni::objects::{GlobalRef, JClass, JObject, JObjectArray, JString, JValueGen};
+use once_cell::sync::OnceCell;
+
+use crate::class::Class;
+use crate::classpool::ClassPool;
+use crate::errors::HierResult as Result;
+
+/// A method of a [Class], backed by a `java.lang.reflect.Method` object.
+///
+/// Immutable informations, e.g. name and modifiers, are cached after the first fetch.
+#[derive(Debug, Clone)]
+pub struct Method {
+    inner: GlobalRef,
+    name: OnceCell<String>,
+    modifiers: OnceCell<u16>,
+}
+
+impl Method {
+    /// Creates new [Method] from an [GlobalRef] that stores reference to
+    /// `java.lang.reflect.Method` as internal backend.
+    pub(crate) fn new(method_obj: GlobalRef) -> Self {
+        Self {
+            inner: method_obj,
+            name: OnceCell::new(),
+            modifiers: OnceCell::new(),
+        }
+    }
+
+    /// Collects methods from a `java.lang.reflect.Method[]`.
+    pub(crate) fn from_array(
+        cp: &mut ClassPool<'_>,
+        method_arr: &JObjectArray<'_>,
+    ) -> Result<Vec<Self>> {
+        let methods_len = cp.get_array_length(method_arr)?;
+        let mut methods = Vec::with_capacity(methods_len as usize);
+
+        for i in 0..methods_len {
+            let method = cp.get_object_array_element(method_arr, i)?;
+            let method = cp.auto_local(method);
+
+            methods.push(Self::new(cp.new_global_ref(&*method)?));
+        }
+
+        Ok(methods)
+    }
+
+    /// Fetches method name.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Method#getName`.
+    pub fn name(&mut self, cp: &mut ClassPool<'_>) -> Result<String> {
+        self.name
+            .get_or_try_init(|| {
+                cp.with_local_frame(1, |env| {
+                    let name: JString = env
+                        .call_method(&self.inner, "getName", "()Ljava/lang/String;", &[])
+                        .and_then(JValueGen::l)?
+                        .into();
+
+                    env.get_string(&name).map(Into::into).map_err(Into::into)
+                })
+            })
+            .cloned()
+    }
+
+    /// Returns method's access flags. See [Modifiers](crate::modifiers::Modifiers) for all
+    /// possible modifiers that would OR-ing together.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Method#getModifiers`.
+    pub fn modifiers(&mut self, cp: &mut ClassPool<'_>) -> Result<u16> {
+        self.modifiers
+            .get_or_try_init(|| {
+                cp.call_method(&self.inner, "getModifiers", "()I", &[])
+                    .and_then(JValueGen::i)
+                    .map(|modifiers| modifiers as u16)
+            })
+            .copied()
+            .map_err(Into::into)
+    }
+
+    /// Returns the [Class] which declares this method.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Method#getDeclaringClass`.
+    pub fn declaring_class(&mut self, cp: &mut ClassPool<'_>) -> Result<Class> {
+        self.class_of(cp, "getDeclaringClass")
+    }
+
+    /// Returns the [Class] of this method's return type.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Method#getReturnType`.
+    pub fn return_type(&mut self, cp: &mut ClassPool<'_>) -> Result<Class> {
+        self.class_of(cp, "getReturnType")
+    }
+
+    /// Returns the [Class]es of this method's parameter types in declaration order.
+    ///
+    /// This function is equivalent to `java.lang.reflect.Method#getParameterTypes`.
+    pub fn parameter_types(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<Class>> {
+        cp.push_local_frame(1)?;
+        let parameter_arr: JObjectArray = cp
+            .call_method(
+                &self.inner,
+                "getParameterTypes",
+                "()[Ljava/lang/Class;",
+                &[],
+            )
+            .and_then(JValueGen::l)?
+            .into();
+        let parameters_len = cp.get_array_length(&parameter_arr)?;
+        let mut parameter_types = Vec::with_capacity(parameters_len as usize);
+
+        for i in 0..parameters_len {
+            let parameter_type = cp.get_object_array_element(&parameter_arr, i)?.into();
+            let parameter_type = cp.fetch_class_from_jclass(&parameter_type, None)?;
+
+            parameter_types.push(Class::new(parameter_type));
+        }
+
+        unsafe {
+            cp.pop_local_frame(&JObject::null())?;
+        }
+
+        Ok(parameter_types)
+    }
+
+    /// Returns class names of annotation types present on this method, annotations are
+    /// only visible if they are retained at runtime.
+    ///
+    /// This function is equivalent to calling `annotationType().getName()` on each
+    /// annotation returned by `java.lang.reflect.Method#getAnnotations`.
+    pub fn annotation_type_names(&mut self, cp: &mut ClassPool<'_>) -> Result<Vec<String>> {
+        cp.annotation_type_names(&self.inner)
+    }
+
+    fn class_of(&self, cp: &mut ClassPool<'_>, getter_name: &str) -> Result<Class> {
+        let class: JClass = cp
+            .call_method(&self.inner, getter_name, "()Ljava/lang/Class;", &[])
+            .and_then(JValueGen::l)?
+            .into();
+        let class = cp.auto_local(class);
+
+        cp.fetch_class_from_jclass(&class, None).map(Class::new)
+    }
+}
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use crate::{classpool::ClassPool, errors::HierResult, modifiers::Modifiers};
+
+    #[test]
+    fn test_method_info() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.lang.Integer")?;
+        let mut parse_int = None;
+
+        for mut method in class.declared_methods(&mut cp)? {
+            if method.name(&mut cp)? == "parseInt" && method.parameter_types(&mut cp)?.len() == 2 {
+                parse_int = Some(method);
+                break;
+            }
+        }
+
+        let mut method = parse_int.expect("Integer.parseInt(String, int) should exist");
+        let parameter_names = method
+            .parameter_types(&mut cp)?
+            .iter_mut()
+            .map(|parameter_type| parameter_type.name(&mut cp))
+            .collect::<HierResult<Vec<_>>>()?;
+
+        assert_eq!(parameter_names, ["java.lang.String", "int"]);
+        assert_eq!(method.return_type(&mut cp)?.name(&mut cp)?, "int");
+        assert_eq!(
+            method.declaring_class(&mut cp)?.name(&mut cp)?,
+            "java.lang.Integer"
+        );
+        assert!(Modifiers::is_static_bits(method.modifiers(&mut cp)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_type_names() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+        let mut class = cp.lookup_class("java.util.Date")?;
+        let mut get_year = None;
+
+        for mut method in class.declared_methods(&mut cp)? {
+            if method.name(&mut cp)? == "getYear" {
+                get_year = Some(method);
+                break;
+            }
+        }
+
+        let mut method = get_year.expect("Date.getYear() should exist");
+
+        assert_eq!(
+            method.annotation_type_names(&mut cp)?,
+            ["java.lang.Deprecated"]
+        );
+
+        Ok(())
+    }
+}
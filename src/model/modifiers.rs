@@ -39,7 +39,7 @@ macro_rules! __impl_flag_chk {
             #[doc = "Determine if provided [u16] has flag"]
             #[doc = $flag_ref]
             pub const fn [<is_ $flag:lower _bits>](bits: u16) -> bool {
-                bits & Self::$flag != 1
+                bits & Self::$flag != 0
             }
 
             #[doc = "Determine if [Modifiers] has flag"]
@@ -51,6 +51,15 @@ macro_rules! __impl_flag_chk {
     };
 }
 
+macro_rules! __impl_builder_flag {
+    ($name:ident, $flag:ident) => {
+        #[doc = concat!("Adds [Modifiers::", stringify!($flag), "]")]
+        pub const fn $name(self) -> Self {
+            Self(self.0.union(Modifiers::$flag))
+        }
+    };
+}
+
 bitflags! {
     #[derive(Clone, Copy, PartialEq, Eq)]
     pub struct Modifiers: u16 {
@@ -112,6 +121,81 @@ impl Modifiers {
     __impl_flag_chk!(Annotation as u16);
     __impl_flag_chk!(Enum as u16);
     __impl_flag_chk!(Mandated as u16);
+
+    /// Creates an empty [ModifiersBuilder].
+    pub const fn builder() -> ModifiersBuilder {
+        ModifiersBuilder::new()
+    }
+
+    /// Determine if provided [u16] has none of flags in [Modifiers::ACCESS_MODIFIERS]
+    pub const fn is_package_private_bits(bits: u16) -> bool {
+        Self::from_bits_truncate(bits).is_package_private()
+    }
+
+    /// Determine if [Modifiers] has none of flags in [Modifiers::ACCESS_MODIFIERS]
+    pub const fn is_package_private(&self) -> bool {
+        !self.has_any_access()
+    }
+
+    /// Determine if [Modifiers] has any of flags in [Modifiers::ACCESS_MODIFIERS]
+    pub const fn has_any_access(&self) -> bool {
+        self.has_any_of(Self::ACCESS_MODIFIERS)
+    }
+
+    /// Determine if [Modifiers] has any of flags in given mask, always false for an empty
+    /// mask
+    pub const fn has_any_of(&self, mask: Modifiers) -> bool {
+        self.intersects(mask)
+    }
+
+    /// Determine if [Modifiers] has all of flags in given mask, always true for an empty
+    /// mask
+    pub const fn has_all_of(&self, mask: Modifiers) -> bool {
+        self.contains(mask)
+    }
+}
+
+/// A builder to construct [Modifiers] by chaining flags, instead of OR-ing them together.
+///
+/// # Example
+///
+/// ```rs
+/// let modifiers = Modifiers::builder().public().final_().build();
+///
+/// assert_eq!(modifiers, Modifiers::Public | Modifiers::Final);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModifiersBuilder(Modifiers);
+
+impl ModifiersBuilder {
+    /// Creates an empty [ModifiersBuilder].
+    pub const fn new() -> Self {
+        Self(Modifiers::empty())
+    }
+
+    __impl_builder_flag!(public, Public);
+    __impl_builder_flag!(private, Private);
+    __impl_builder_flag!(protected, Protected);
+    __impl_builder_flag!(static_, Static);
+    __impl_builder_flag!(final_, Final);
+    __impl_builder_flag!(synchronized, Synchronized);
+    __impl_builder_flag!(volatile, Volatile);
+    __impl_builder_flag!(transient, Transient);
+    __impl_builder_flag!(native, Native);
+    __impl_builder_flag!(interface, Interface);
+    __impl_builder_flag!(abstract_, Abstract);
+    __impl_builder_flag!(strict, Strict);
+
+    /// Builds [Modifiers] with added flags.
+    pub const fn build(self) -> Modifiers {
+        self.0
+    }
+}
+
+impl Default for ModifiersBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Debug for Modifiers {
@@ -125,3 +209,125 @@ impl Display for Modifiers {
         fmt::Display::fmt(&self.0, f)
     }
 }
+
+/// Serializes [Modifiers] as its raw bits.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Modifiers {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(self.bits())
+    }
+}
+
+/// Deserializes [Modifiers] from raw bits, unknown bits are truncated.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Modifiers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        u16::deserialize(deserializer).map(Self::from_bits_truncate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::modifiers::{Modifiers, ModifiersBuilder};
+
+    #[rstest]
+    #[case(0x0000, false, false, false, false)]
+    #[case(0x0001, true, false, false, false)]
+    #[case(0x0008, false, true, false, false)]
+    #[case(0x1000, false, false, true, false)]
+    #[case(0x2000 | 0x0200, false, false, false, true)]
+    #[case(0x1000 | 0x2000 | 0x0009, true, true, true, true)]
+    fn test_is_flag_bits(
+        #[case] bits: u16,
+        #[case] is_public: bool,
+        #[case] is_static: bool,
+        #[case] is_synthetic: bool,
+        #[case] is_annotation: bool,
+    ) {
+        assert_eq!(Modifiers::is_public_bits(bits), is_public);
+        assert_eq!(Modifiers::is_static_bits(bits), is_static);
+        assert_eq!(Modifiers::is_synthetic_bits(bits), is_synthetic);
+        assert_eq!(Modifiers::is_annotation_bits(bits), is_annotation);
+    }
+
+    #[rstest]
+    #[case(0x0000, true)]
+    #[case(0x0001, false)]
+    #[case(0x0002, false)]
+    #[case(0x0004, false)]
+    #[case(0x0010 | 0x0400, true)]
+    #[case(0x0001 | 0x0010, false)]
+    fn test_is_package_private(#[case] bits: u16, #[case] is_package_private: bool) {
+        assert_eq!(Modifiers::is_package_private_bits(bits), is_package_private);
+        assert_eq!(
+            Modifiers::from_bits_truncate(bits).is_package_private(),
+            is_package_private
+        );
+    }
+
+    #[rstest]
+    #[case(Modifiers::empty(), false)]
+    #[case(Modifiers::Public, true)]
+    #[case(Modifiers::Private | Modifiers::Static, true)]
+    #[case(Modifiers::Protected | Modifiers::Final, true)]
+    #[case(Modifiers::Static | Modifiers::Final | Modifiers::Abstract, false)]
+    fn test_has_any_access(#[case] modifiers: Modifiers, #[case] has_any_access: bool) {
+        assert_eq!(modifiers.has_any_access(), has_any_access);
+        assert_eq!(modifiers.is_package_private(), !has_any_access);
+    }
+
+    #[rstest]
+    #[case(Modifiers::Public | Modifiers::Static, Modifiers::Static | Modifiers::Final, true, false)]
+    #[case(Modifiers::Public | Modifiers::Static, Modifiers::Public | Modifiers::Static, true, true)]
+    #[case(Modifiers::Public | Modifiers::Static | Modifiers::Final, Modifiers::Public, true, true)]
+    #[case(Modifiers::Public, Modifiers::Private | Modifiers::Protected, false, false)]
+    #[case(Modifiers::Public, Modifiers::empty(), false, true)]
+    #[case(Modifiers::empty(), Modifiers::FIELD_MODIFIERS, false, false)]
+    fn test_has_any_and_all_of(
+        #[case] modifiers: Modifiers,
+        #[case] mask: Modifiers,
+        #[case] has_any_of: bool,
+        #[case] has_all_of: bool,
+    ) {
+        assert_eq!(modifiers.has_any_of(mask), has_any_of);
+        assert_eq!(modifiers.has_all_of(mask), has_all_of);
+    }
+
+    #[test]
+    fn test_builder() {
+        assert_eq!(
+            Modifiers::builder().public().final_().build(),
+            Modifiers::Public | Modifiers::Final
+        );
+        assert_eq!(
+            ModifiersBuilder::new()
+                .protected()
+                .static_()
+                .abstract_()
+                .build(),
+            Modifiers::Protected | Modifiers::Static | Modifiers::Abstract
+        );
+        assert_eq!(ModifiersBuilder::default().build(), Modifiers::empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let modifiers = Modifiers::Public | Modifiers::Final;
+        let json = serde_json::to_string(&modifiers).expect("modifiers should serialize");
+
+        assert_eq!(json, "17");
+        assert_eq!(
+            serde_json::from_str::<Modifiers>(&json).expect("modifiers should deserialize"),
+            modifiers
+        );
+    }
+}
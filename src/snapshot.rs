@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{class::Class, classpool::ClassPool, errors::HierResult as Result};
+
+/// A portable snapshot of a [Class], which can be serialized and inspected later without
+/// a running JVM, see [`ClassPool::snapshot_all`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassSnapshot {
+    /// Class name in `java.lang.Class#getName`'s form.
+    pub name: String,
+    /// Class access flags, see [Modifiers](crate::modifiers::Modifiers).
+    pub modifiers: u16,
+    /// Superclass name, [None] if class is `java.lang.Object`, an interface, a primitive
+    /// type or `void`.
+    pub superclass_name: Option<String>,
+    /// Names of directly implemented (or extended) interfaces in declaration order.
+    pub interface_names: Vec<String>,
+}
+
+impl ClassSnapshot {
+    /// Captures a snapshot of given [Class].
+    pub fn capture(cp: &mut ClassPool<'_>, class: &mut Class) -> Result<Self> {
+        let superclass_name = class
+            .superclass(cp)?
+            .map(|mut superclass| superclass.name(cp))
+            .transpose()?;
+        let interface_names = class
+            .interfaces(cp)?
+            .iter_mut()
+            .map(|interface| interface.name(cp))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: class.name(cp)?,
+            modifiers: class.modifiers(cp)?,
+            superclass_name,
+            interface_names,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "invocation"))]
+mod test {
+    use crate::{classpool::ClassPool, errors::HierResult, snapshot::ClassSnapshot};
+
+    #[test]
+    fn test_snapshot_all() -> HierResult<()> {
+        let mut cp = ClassPool::from_permanent_env()?;
+
+        for class_path in ["java.lang.Object", "java.lang.Number", "java.lang.Integer"] {
+            cp.lookup_class(class_path)?;
+        }
+
+        let snapshots = cp.snapshot_all()?;
+        let names = snapshots
+            .iter()
+            .map(|snapshot| snapshot.name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            ["java.lang.Integer", "java.lang.Number", "java.lang.Object"]
+        );
+        assert_eq!(
+            snapshots[0].superclass_name.as_deref(),
+            Some("java.lang.Number")
+        );
+        assert!(snapshots[0]
+            .interface_names
+            .contains(&"java.lang.Comparable".to_string()));
+        assert_eq!(snapshots[2].superclass_name, None);
+
+        let json = serde_json::to_string(&snapshots).expect("snapshots should serialize");
+        let deserialized: Vec<ClassSnapshot> =
+            serde_json::from_str(&json).expect("snapshots should deserialize");
+
+        assert_eq!(deserialized, snapshots);
+
+        Ok(())
+    }
+}
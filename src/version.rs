@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Java version, versions are ordered by their release order, while [JavaVersion::Invalid]
+/// is greater than all known versions.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum JavaVersion {
     V0,
     V1,
@@ -29,6 +31,147 @@ pub enum JavaVersion {
     Invalid(String),
 }
 
+impl JavaVersion {
+    /// The feature number of the latest version known by this crate.
+    const LATEST_FEATURE_NUMBER: u32 = 23;
+
+    /// Returns an iterator over all known versions between `start` and `end` inclusively,
+    /// [JavaVersion::Invalid] is never yielded.
+    ///
+    /// # Example
+    ///
+    /// ```rs
+    /// let versions = JavaVersion::range(JavaVersion::V8, JavaVersion::V11).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(versions, [JavaVersion::V8, JavaVersion::V9, JavaVersion::V10, JavaVersion::V11]);
+    /// ```
+    pub fn range(start: JavaVersion, end: JavaVersion) -> impl Iterator<Item = JavaVersion> {
+        (0..=Self::LATEST_FEATURE_NUMBER)
+            .map(JavaVersion::from)
+            .filter(move |version| *version >= start && *version <= end)
+    }
+
+    /// Returns the Java version selected at build time by `--cfg jvm_v<feature>` (e.g.
+    /// `RUSTFLAGS="--cfg jvm_v17"`), or [None] if no version is selected. Unlike
+    /// [`HierExt::get_java_version`](crate::HierExt::get_java_version), this doesn't require
+    /// a live JNI environment.
+    pub const fn from_cfg() -> Option<JavaVersion> {
+        let version = if cfg!(jvm_v0) {
+            Self::V0
+        } else if cfg!(jvm_v1) {
+            Self::V1
+        } else if cfg!(jvm_v2) {
+            Self::V2
+        } else if cfg!(jvm_v3) {
+            Self::V3
+        } else if cfg!(jvm_v4) {
+            Self::V4
+        } else if cfg!(jvm_v5) {
+            Self::V5
+        } else if cfg!(jvm_v6) {
+            Self::V6
+        } else if cfg!(jvm_v7) {
+            Self::V7
+        } else if cfg!(jvm_v8) {
+            Self::V8
+        } else if cfg!(jvm_v9) {
+            Self::V9
+        } else if cfg!(jvm_v10) {
+            Self::V10
+        } else if cfg!(jvm_v11) {
+            Self::V11
+        } else if cfg!(jvm_v12) {
+            Self::V12
+        } else if cfg!(jvm_v13) {
+            Self::V13
+        } else if cfg!(jvm_v14) {
+            Self::V14
+        } else if cfg!(jvm_v15) {
+            Self::V15
+        } else if cfg!(jvm_v16) {
+            Self::V16
+        } else if cfg!(jvm_v17) {
+            Self::V17
+        } else if cfg!(jvm_v18) {
+            Self::V18
+        } else if cfg!(jvm_v19) {
+            Self::V19
+        } else if cfg!(jvm_v20) {
+            Self::V20
+        } else if cfg!(jvm_v21) {
+            Self::V21
+        } else if cfg!(jvm_v22) {
+            Self::V22
+        } else if cfg!(jvm_v23) {
+            Self::V23
+        } else {
+            return None;
+        };
+
+        Some(version)
+    }
+
+    /// Determines if records (`java.lang.Record`) are supported, introduced in Java 16.
+    pub fn supports_records(&self) -> bool {
+        self.is_at_least(16)
+    }
+
+    /// Determines if sealed classes are supported, introduced in Java 17.
+    pub fn supports_sealed_classes(&self) -> bool {
+        self.is_at_least(17)
+    }
+
+    /// Determines if modules (`java.lang.Module`) are supported, introduced in Java 9.
+    pub fn supports_modules(&self) -> bool {
+        self.is_at_least(9)
+    }
+
+    /// Determines if local variable type inference (`var`) is supported, introduced in Java 10.
+    pub fn supports_var(&self) -> bool {
+        self.is_at_least(10)
+    }
+
+    /// Determines if current version is at least given feature version, an
+    /// [JavaVersion::Invalid] version is compared by its leading number if possible (e.g.
+    /// a version newer than this crate knows), otherwise it's never at least any version.
+    fn is_at_least(&self, feature: u32) -> bool {
+        self.feature_number()
+            .is_some_and(|number| number >= feature)
+    }
+
+    fn feature_number(&self) -> Option<u32> {
+        let number = match self {
+            Self::V0 => 0,
+            Self::V1 => 1,
+            Self::V2 => 2,
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V5 => 5,
+            Self::V6 => 6,
+            Self::V7 => 7,
+            Self::V8 => 8,
+            Self::V9 => 9,
+            Self::V10 => 10,
+            Self::V11 => 11,
+            Self::V12 => 12,
+            Self::V13 => 13,
+            Self::V14 => 14,
+            Self::V15 => 15,
+            Self::V16 => 16,
+            Self::V17 => 17,
+            Self::V18 => 18,
+            Self::V19 => 19,
+            Self::V20 => 20,
+            Self::V21 => 21,
+            Self::V22 => 22,
+            Self::V23 => 23,
+            Self::Invalid(version) => return version.split('.').next()?.parse().ok(),
+        };
+
+        Some(number)
+    }
+}
+
 impl From<String> for JavaVersion {
     /// This conversion is compatible for "java.version" and "java.specification.version"
     /// poperties.
@@ -72,10 +215,105 @@ impl From<String> for JavaVersion {
     }
 }
 
+impl From<u32> for JavaVersion {
+    /// Converts JDK feature version number (e.g. `Runtime.version().feature()`) into
+    /// [JavaVersion], `1.0` is mapped from `0`.
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::V0,
+            1 => Self::V1,
+            2 => Self::V2,
+            3 => Self::V3,
+            4 => Self::V4,
+            5 => Self::V5,
+            6 => Self::V6,
+            7 => Self::V7,
+            8 => Self::V8,
+            9 => Self::V9,
+            10 => Self::V10,
+            11 => Self::V11,
+            12 => Self::V12,
+            13 => Self::V13,
+            14 => Self::V14,
+            15 => Self::V15,
+            16 => Self::V16,
+            17 => Self::V17,
+            18 => Self::V18,
+            19 => Self::V19,
+            20 => Self::V20,
+            21 => Self::V21,
+            22 => Self::V22,
+            23 => Self::V23,
+            _ => Self::Invalid(value.to_string()),
+        }
+    }
+}
+
+impl From<i32> for JavaVersion {
+    /// Converts JDK feature version number into [JavaVersion], negative numbers are
+    /// converted into [JavaVersion::Invalid].
+    fn from(value: i32) -> Self {
+        u32::try_from(value)
+            .map(Self::from)
+            .unwrap_or_else(|_| Self::Invalid(value.to_string()))
+    }
+}
+
 #[cfg(all(test, feature = "invocation"))]
 mod test {
+    use rstest::rstest;
+
     use crate::{classpool::ClassPool, errors::HierResult, version::JavaVersion, HierExt};
 
+    #[rstest]
+    #[case(0, JavaVersion::V0)]
+    #[case(8, JavaVersion::V8)]
+    #[case(17, JavaVersion::V17)]
+    #[case(23, JavaVersion::V23)]
+    #[case(24, JavaVersion::Invalid("24".to_string()))]
+    #[case(-1, JavaVersion::Invalid("-1".to_string()))]
+    fn test_from_feature_number(#[case] feature: i32, #[case] expected: JavaVersion) {
+        assert_eq!(JavaVersion::from(feature), expected);
+
+        if let Ok(feature) = u32::try_from(feature) {
+            assert_eq!(JavaVersion::from(feature), expected);
+        }
+    }
+
+    #[rstest]
+    #[case(JavaVersion::V8, JavaVersion::V11, &[JavaVersion::V8, JavaVersion::V9, JavaVersion::V10, JavaVersion::V11])]
+    #[case(JavaVersion::V17, JavaVersion::V17, &[JavaVersion::V17])]
+    #[case(JavaVersion::V11, JavaVersion::V8, &[])]
+    #[case(JavaVersion::V22, JavaVersion::Invalid("24".to_string()), &[JavaVersion::V22, JavaVersion::V23])]
+    fn test_range(
+        #[case] start: JavaVersion,
+        #[case] end: JavaVersion,
+        #[case] versions: &[JavaVersion],
+    ) {
+        assert_eq!(JavaVersion::range(start, end).collect::<Vec<_>>(), versions);
+    }
+
+    #[rstest]
+    #[case(JavaVersion::V8, false, false, false, false)]
+    #[case(JavaVersion::V9, false, false, true, false)]
+    #[case(JavaVersion::V10, false, false, true, true)]
+    #[case(JavaVersion::V16, true, false, true, true)]
+    #[case(JavaVersion::V17, true, true, true, true)]
+    #[case(JavaVersion::Invalid("24".to_string()), true, true, true, true)]
+    #[case(JavaVersion::Invalid("unknown".to_string()), false, false, false, false)]
+    fn test_supports_features(
+        #[case] version: JavaVersion,
+        #[case] records: bool,
+        #[case] sealed_classes: bool,
+        #[case] modules: bool,
+        #[case] var: bool,
+    ) {
+        assert_eq!(version.supports_records(), records);
+        assert_eq!(version.supports_sealed_classes(), sealed_classes);
+        assert_eq!(version.supports_modules(), modules);
+        assert_eq!(version.supports_var(), var);
+    }
+
     #[test]
     #[cfg_attr(
         not(any(
@@ -87,57 +325,8 @@ mod test {
     )]
     /// Tests all possible jvm versions
     fn test_jvm_version() -> HierResult<()> {
-        let current_jvm_version: JavaVersion = if cfg!(jvm_v0) {
-            JavaVersion::V0
-        } else if cfg!(jvm_v1) {
-            JavaVersion::V1
-        } else if cfg!(jvm_v2) {
-            JavaVersion::V2
-        } else if cfg!(jvm_v3) {
-            JavaVersion::V3
-        } else if cfg!(jvm_v4) {
-            JavaVersion::V4
-        } else if cfg!(jvm_v5) {
-            JavaVersion::V5
-        } else if cfg!(jvm_v6) {
-            JavaVersion::V6
-        } else if cfg!(jvm_v7) {
-            JavaVersion::V7
-        } else if cfg!(jvm_v8) {
-            JavaVersion::V8
-        } else if cfg!(jvm_v9) {
-            JavaVersion::V9
-        } else if cfg!(jvm_v10) {
-            JavaVersion::V10
-        } else if cfg!(jvm_v11) {
-            JavaVersion::V11
-        } else if cfg!(jvm_v12) {
-            JavaVersion::V12
-        } else if cfg!(jvm_v13) {
-            JavaVersion::V13
-        } else if cfg!(jvm_v14) {
-            JavaVersion::V14
-        } else if cfg!(jvm_v15) {
-            JavaVersion::V15
-        } else if cfg!(jvm_v16) {
-            JavaVersion::V16
-        } else if cfg!(jvm_v17) {
-            JavaVersion::V17
-        } else if cfg!(jvm_v18) {
-            JavaVersion::V18
-        } else if cfg!(jvm_v19) {
-            JavaVersion::V19
-        } else if cfg!(jvm_v20) {
-            JavaVersion::V20
-        } else if cfg!(jvm_v21) {
-            JavaVersion::V21
-        } else if cfg!(jvm_v22) {
-            JavaVersion::V22
-        } else if cfg!(jvm_v23) {
-            JavaVersion::V23
-        } else {
-            unreachable!()
-        };
+        let current_jvm_version =
+            JavaVersion::from_cfg().expect("Java version should be provided by cfg");
 
         let mut cp = ClassPool::from_permanent_env()?;
         let version = cp.get_java_version()?;
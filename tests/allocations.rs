@@ -0,0 +1,55 @@
+#![cfg(feature = "invocation")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use hier::{classpath::ClassPath, classpool::ClassPool, errors::HierResult};
+
+/// Counts allocations made through Rust's global allocator on current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn test_repeated_lookup_does_not_allocate() -> HierResult<()> {
+    let mut cp = ClassPool::from_permanent_env()?;
+    let java_cp = ClassPath::from("java.lang.String");
+    let jni_cp = ClassPath::JNI("java/lang/String".to_string());
+
+    cp.lookup_class("java.lang.String")?;
+
+    for _ in 0..16 {
+        let before = allocations();
+        let class = cp.lookup_class("java.lang.String")?;
+        let java_class = cp.lookup_class_path(&java_cp)?;
+        let jni_class = cp.lookup_class_path(&jni_cp)?;
+
+        assert_eq!(allocations(), before);
+
+        drop((class, java_class, jni_class));
+    }
+
+    Ok(())
+}
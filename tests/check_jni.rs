@@ -0,0 +1,48 @@
+#![cfg(feature = "invocation")]
+
+use hier::{
+    classpool::ClassPool,
+    errors::{HierError, HierResult},
+    jni::objects::JValueGen,
+};
+
+#[test]
+fn test_from_permanent_env_checked() -> HierResult<()> {
+    let mut cp = ClassPool::from_permanent_env_checked(false)?;
+    let runtime = cp
+        .call_static_method(
+            "java/lang/management/ManagementFactory",
+            "getRuntimeMXBean",
+            "()Ljava/lang/management/RuntimeMXBean;",
+            &[],
+        )
+        .and_then(JValueGen::l)?;
+    let arguments = cp
+        .call_method(runtime, "getInputArguments", "()Ljava/util/List;", &[])
+        .and_then(JValueGen::l)?;
+    let arguments_len = cp
+        .call_method(&arguments, "size", "()I", &[])
+        .and_then(JValueGen::i)?;
+
+    for i in 0..arguments_len {
+        let argument = cp
+            .call_method(
+                &arguments,
+                "get",
+                "(I)Ljava/lang/Object;",
+                &[JValueGen::Int(i)],
+            )
+            .and_then(JValueGen::l)?;
+        let argument: String = cp.get_string(&argument.into())?.into();
+
+        assert_ne!(argument, "-Xcheck:jni");
+    }
+
+    assert!(ClassPool::from_permanent_env_checked(false).is_ok());
+    assert!(matches!(
+        ClassPool::from_permanent_env_checked(true),
+        Err(HierError::JvmAlreadyInitialized)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use hier::{
+    classpath::ClassPath,
+    errors::{ClassPathError, HierError, HierResult},
+};
+
+fn validate(class_path: &str) -> HierResult<()> {
+    ClassPath::from(class_path).validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_hier_result() {
+    assert!(validate("java.lang.Object").is_ok());
+    assert!(matches!(
+        validate("void[]"),
+        Err(HierError::ClassPath(ClassPathError::VoidArray(_)))
+    ));
+}
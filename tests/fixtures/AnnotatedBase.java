@@ -0,0 +1,5 @@
+package fixtures;
+
+@InheritedMarker
+public class AnnotatedBase {
+}
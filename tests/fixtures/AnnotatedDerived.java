@@ -0,0 +1,5 @@
+package fixtures;
+
+@Marker
+public class AnnotatedDerived extends AnnotatedBase {
+}
@@ -0,0 +1,6 @@
+package fixtures;
+
+public class AnnotatedMembers {
+    @Marker
+    public int value;
+}
@@ -0,0 +1,5 @@
+package fixtures;
+
+@Deprecated
+public class DeprecatedClass {
+}
@@ -0,0 +1,4 @@
+package fixtures;
+
+public interface DiamondB extends DiamondA {
+}
@@ -0,0 +1,4 @@
+package fixtures;
+
+public interface DiamondC extends DiamondA {
+}
@@ -0,0 +1,4 @@
+package fixtures;
+
+public interface DiamondD extends DiamondB, DiamondC {
+}
@@ -0,0 +1,4 @@
+package fixtures;
+
+public class DiamondImpl implements DiamondC, DiamondB {
+}
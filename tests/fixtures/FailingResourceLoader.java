@@ -0,0 +1,61 @@
+package fixtures;
+
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+
+/**
+ * Defines {@code fixtures.DeprecatedClass} by itself, and serves resources of it with streams
+ * that fail on every read.
+ */
+public class FailingResourceLoader extends ClassLoader {
+    public static boolean closed;
+
+    public FailingResourceLoader(ClassLoader parent) {
+        super(parent);
+    }
+
+    @Override
+    protected Class<?> loadClass(String name, boolean resolve) throws ClassNotFoundException {
+        if (!name.equals("fixtures.DeprecatedClass")) {
+            return super.loadClass(name, resolve);
+        }
+
+        synchronized (getClassLoadingLock(name)) {
+            Class<?> loaded = findLoadedClass(name);
+
+            if (loaded != null) {
+                return loaded;
+            }
+
+            try (InputStream stream = getParent().getResourceAsStream("fixtures/DeprecatedClass.class")) {
+                ByteArrayOutputStream bytes = new ByteArrayOutputStream();
+                byte[] buffer = new byte[8192];
+                int readLen;
+
+                while ((readLen = stream.read(buffer)) >= 0) {
+                    bytes.write(buffer, 0, readLen);
+                }
+
+                return defineClass(name, bytes.toByteArray(), 0, bytes.size());
+            } catch (IOException e) {
+                throw new ClassNotFoundException(name, e);
+            }
+        }
+    }
+
+    @Override
+    public InputStream getResourceAsStream(String name) {
+        return new InputStream() {
+            @Override
+            public int read() throws IOException {
+                throw new IOException("broken stream");
+            }
+
+            @Override
+            public void close() {
+                closed = true;
+            }
+        };
+    }
+}
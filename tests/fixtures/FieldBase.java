@@ -0,0 +1,5 @@
+package fixtures;
+
+public class FieldBase {
+    public int value;
+}
@@ -0,0 +1,4 @@
+package fixtures;
+
+public class FieldDerived extends FieldBase {
+}
@@ -0,0 +1,5 @@
+package fixtures;
+
+public class FieldShadowed extends FieldDerived {
+    public long value;
+}
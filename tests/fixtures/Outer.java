@@ -0,0 +1,12 @@
+package fixtures;
+
+public class Outer {
+    public static class StaticNested {
+    }
+
+    public class Inner {
+        public Outer outer() {
+            return Outer.this;
+        }
+    }
+}
@@ -0,0 +1,6 @@
+package fixtures;
+
+class PackagePrivate {
+    public static class Nested {
+    }
+}
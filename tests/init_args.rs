@@ -0,0 +1,41 @@
+#![cfg(feature = "invocation")]
+
+use hier::{
+    classpool::ClassPool,
+    errors::{HierError, HierResult},
+    jni::{InitArgsBuilder, JNIVersion},
+    HierExt,
+};
+
+#[test]
+fn test_from_permanent_env_with() -> HierResult<()> {
+    let jvm_args = InitArgsBuilder::new()
+        .version(JNIVersion::V8)
+        .option("-Djava.awt.headless=true")
+        .build()?;
+    let mut cp = ClassPool::from_permanent_env_with(jvm_args)?;
+    let properties = cp.system_properties()?;
+
+    assert_eq!(
+        properties.get("java.awt.headless").map(String::as_str),
+        Some("true")
+    );
+
+    let jvm_args = InitArgsBuilder::new().version(JNIVersion::V8).build()?;
+
+    assert!(matches!(
+        ClassPool::from_permanent_env_with(jvm_args),
+        Err(HierError::JvmAlreadyInitialized)
+    ));
+
+    let mut cp = ClassPool::from_permanent_env()?;
+
+    assert_eq!(
+        cp.system_properties()?
+            .get("java.awt.headless")
+            .map(String::as_str),
+        Some("true")
+    );
+
+    Ok(())
+}